@@ -8,19 +8,16 @@ entry!(main);
 fn main() -> ! {
 
     // Get peripherals
-    let dp = peripherals::Peripherals::take();
+    let dp = peripherals::Peripherals::take().unwrap();
    
-    // get Rcc 
-    let rcc = dp.rcc;
+    // get the Rcc bus handle, used to enable the peripherals
+    let rcc = dp.rcc.constrain();
 
-    // enable GpioA clock
-    rcc.enable_gpioa();
-
-    // get GpioA
-    let gpioa = dp.gpioa;
+    // split GpioA into its pins (this also enables and resets GpioA)
+    let gpioa = dp.gpioa.split(rcc.bus);
 
     // Configure PA1 as output
-    let pa1 = gpioa.p1().into_output_pushpull(PinSpeed::Speed2Hz);
+    let pa1 = gpioa.p1.into_output_pushpull(PinSpeed::Speed2Hz);
 
     // Toggle the LED on PA1
     loop {
@@ -16,6 +16,7 @@ SECTIONS
     LONG(ORIGIN(RAM) + LENGTH(RAM));
     KEEP(*(.vector_table.reset_vector));
     KEEP(*(.vector_table.exceptions));
+    KEEP(*(.vector_table.interrupts));
     . = ALIGN(4) ;
   } > FLASH
 
@@ -63,4 +64,65 @@ SECTIONS
   PROVIDE(SVCall = DefaultExceptionHandler);
   PROVIDE(PendSV = DefaultExceptionHandler);
   PROVIDE(SysTick = DefaultExceptionHandler);
+
+  PROVIDE(WWDG = DefaultExceptionHandler);
+  PROVIDE(PVD = DefaultExceptionHandler);
+  PROVIDE(TAMPER = DefaultExceptionHandler);
+  PROVIDE(RTC = DefaultExceptionHandler);
+  PROVIDE(FLASH = DefaultExceptionHandler);
+  PROVIDE(RCC = DefaultExceptionHandler);
+  PROVIDE(EXTI0 = DefaultExceptionHandler);
+  PROVIDE(EXTI1 = DefaultExceptionHandler);
+  PROVIDE(EXTI2 = DefaultExceptionHandler);
+  PROVIDE(EXTI3 = DefaultExceptionHandler);
+  PROVIDE(EXTI4 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL1 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL2 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL3 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL4 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL5 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL6 = DefaultExceptionHandler);
+  PROVIDE(DMA1_CHANNEL7 = DefaultExceptionHandler);
+  PROVIDE(ADC1_2 = DefaultExceptionHandler);
+  PROVIDE(USB_HP_CAN_TX = DefaultExceptionHandler);
+  PROVIDE(USB_LP_CAN_RX0 = DefaultExceptionHandler);
+  PROVIDE(CAN_RX1 = DefaultExceptionHandler);
+  PROVIDE(CAN_SCE = DefaultExceptionHandler);
+  PROVIDE(EXTI9_5 = DefaultExceptionHandler);
+  PROVIDE(TIM1_BRK = DefaultExceptionHandler);
+  PROVIDE(TIM1_UP = DefaultExceptionHandler);
+  PROVIDE(TIM1_TRG_COM = DefaultExceptionHandler);
+  PROVIDE(TIM1_CC = DefaultExceptionHandler);
+  PROVIDE(TIM2 = DefaultExceptionHandler);
+  PROVIDE(TIM3 = DefaultExceptionHandler);
+  PROVIDE(TIM4 = DefaultExceptionHandler);
+  PROVIDE(I2C1_EV = DefaultExceptionHandler);
+  PROVIDE(I2C1_ER = DefaultExceptionHandler);
+  PROVIDE(I2C2_EV = DefaultExceptionHandler);
+  PROVIDE(I2C2_ER = DefaultExceptionHandler);
+  PROVIDE(SPI1 = DefaultExceptionHandler);
+  PROVIDE(SPI2 = DefaultExceptionHandler);
+  PROVIDE(USART1 = DefaultExceptionHandler);
+  PROVIDE(USART2 = DefaultExceptionHandler);
+  PROVIDE(USART3 = DefaultExceptionHandler);
+  PROVIDE(EXTI15_10 = DefaultExceptionHandler);
+  PROVIDE(RTCALARM = DefaultExceptionHandler);
+  PROVIDE(USBWAKEUP = DefaultExceptionHandler);
+  PROVIDE(TIM8_BRK = DefaultExceptionHandler);
+  PROVIDE(TIM8_UP = DefaultExceptionHandler);
+  PROVIDE(TIM8_TRG_COM = DefaultExceptionHandler);
+  PROVIDE(TIM8_CC = DefaultExceptionHandler);
+  PROVIDE(ADC3 = DefaultExceptionHandler);
+  PROVIDE(FSMC = DefaultExceptionHandler);
+  PROVIDE(SDIO = DefaultExceptionHandler);
+  PROVIDE(TIM5 = DefaultExceptionHandler);
+  PROVIDE(SPI3 = DefaultExceptionHandler);
+  PROVIDE(UART4 = DefaultExceptionHandler);
+  PROVIDE(UART5 = DefaultExceptionHandler);
+  PROVIDE(TIM6 = DefaultExceptionHandler);
+  PROVIDE(TIM7 = DefaultExceptionHandler);
+  PROVIDE(DMA2_CHANNEL1 = DefaultExceptionHandler);
+  PROVIDE(DMA2_CHANNEL2 = DefaultExceptionHandler);
+  PROVIDE(DMA2_CHANNEL3 = DefaultExceptionHandler);
+  PROVIDE(DMA2_CHANNEL4_5 = DefaultExceptionHandler);
 }
@@ -0,0 +1,730 @@
+use embedded_storage::nor_flash::NorFlash;
+
+/// Size of a page header: a RECEIVE slot followed by a VALID slot.
+const HEADER_SIZE: u32 = 8;
+
+/// Offset of the RECEIVE slot in the page header. It holds the page generation
+/// followed by its complement.
+const RECEIVE_SLOT: u32 = 0;
+
+/// Offset of the VALID slot in the page header.
+const VALID_SLOT: u32 = 4;
+
+/// Value programmed into the VALID slot.
+const VALID_MARKER: [u8; 4] = [0x00; 4];
+
+/// Size of a record: the value half-word, the key half-word and the COMPLETE word.
+const RECORD_SIZE: u32 = 8;
+
+/// Offset of the COMPLETE word in a record, programmed after the value and key.
+const COMPLETE_SLOT: u32 = 4;
+
+/// Value programmed into the COMPLETE word of a record.
+const COMPLETE_MARKER: [u8; 4] = [0x00; 4];
+
+/// Key of an erased record slot, which cannot be used as a key.
+pub const ERASED_KEY: u16 = 0xFFFF;
+
+/// Error returned by the EEPROM emulation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EepromError<E>
+{
+    /// The underlying flash returned an error.
+    Flash(E),
+    /// The page range does not fit the flash, or its sizes cannot hold records.
+    InvalidConfig,
+    /// `ERASED_KEY` was used as a key.
+    InvalidKey,
+    /// Two pages carry the same generation, so the current one cannot be told apart.
+    Corrupted,
+    /// No page holds a complete header: the range is blank, holds other data, or its
+    /// formatting was cut. It is only erased by `Eeprom::new_formatted()`.
+    NotFormatted,
+    /// All the record slots of a page are taken by distinct keys.
+    Full,
+}
+
+/// State of the VALID slot of a page.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValidMark
+{
+    /// Not programmed: the page is the target of an unfinished swap.
+    Erased,
+    /// Partly programmed: the swap was cut while marking the page valid.
+    Partial,
+    /// Programmed: the page holds complete records.
+    Set,
+}
+
+/// Checks whether generation `a` was written after generation `b`, allowing wrap-around.
+fn is_newer(a: u16, b: u16) -> bool
+{
+    (a.wrapping_sub(b) as i16) > 0
+}
+
+/// Key/value store emulating an EEPROM on flash pages, in the style of ST AN2594.
+///
+/// The store uses `pages` consecutive flash pages of `S::ERASE_SIZE` bytes. One page is
+/// current at a time and records are appended to it. When it is full, the latest value
+/// of every key is copied to the next page of the ring and the old page is erased, so
+/// the wear is spread over all the pages.
+///
+/// Each page starts with a RECEIVE slot holding the page generation and its complement,
+/// and a VALID slot, followed by records of a value half-word, a key half-word and a
+/// COMPLETE word. The COMPLETE word is programmed to zero once the value and key are
+/// written, so a record interrupted by a power loss is never read back. An interrupted
+/// erase either breaks the generation pair or leaves an older generation behind, so it
+/// is never taken for the current page.
+///
+/// A swap runs in this order: erase the next page, write its generation, write the new
+/// record, copy the latest values, mark the new page valid, erase the old page. On
+/// creation the newest complete page is taken, so the store recovers from a swap
+/// interrupted at any of these steps. The pages are set up once with `new_formatted()`;
+/// `new()` never erases a range it does not recognize.
+pub struct Eeprom<S>
+{
+    flash:      S,
+    start:      u32,
+    pages:      u32,
+    active:     u32,
+    generation: u16,
+    next:       u32,
+}
+
+impl<S: NorFlash> Eeprom<S>
+{
+    /// Opens the store, recovering from an interrupted swap.
+    ///
+    /// Returns `EepromError::NotFormatted` without erasing anything if no page holds a
+    /// complete header, e.g. on first use or when `start` is wrong.
+    ///
+    /// # Arguments
+    /// * `flash` - Flash holding the pages, e.g. a `FlashWriter`.
+    /// * `start` - Offset of the first page, a multiple of `S::ERASE_SIZE`.
+    /// * `pages` - Number of pages used, at least 2.
+    ///
+    /// # Example
+    /// ```
+    /// let writer = dp.flash.unlock()?;
+    /// let start = writer.size() - 2 * PAGE_SIZE;
+    /// let mut eeprom = Eeprom::new(writer, start, 2)?;
+    /// eeprom.write(0x0001, 1234)?;
+    /// assert_eq!(eeprom.read(0x0001)?, Some(1234));
+    /// ```
+    pub fn new(flash: S, start: u32, pages: u32) -> Result<Self, EepromError<S::Error>>
+    {
+        let mut eeprom = Self::with_config(flash, start, pages)?;
+        eeprom.recover()?;
+        Ok(eeprom)
+    }
+
+    /// Opens the store after erasing all its pages, discarding any previous content.
+    ///
+    /// This sets up the pages on first use, and is the way out of `NotFormatted` and
+    /// `Corrupted`.
+    pub fn new_formatted(flash: S, start: u32, pages: u32) -> Result<Self, EepromError<S::Error>>
+    {
+        let mut eeprom = Self::with_config(flash, start, pages)?;
+        eeprom.format()?;
+        Ok(eeprom)
+    }
+
+    /// Checks the page range against the flash and builds the store without touching it.
+    fn with_config(flash: S, start: u32, pages: u32) -> Result<Self, EepromError<S::Error>>
+    {
+        let page_size = S::ERASE_SIZE as u32;
+        let valid_config = pages >= 2
+            && start.is_multiple_of(page_size)
+            && page_size >= HEADER_SIZE + RECORD_SIZE
+            && RECORD_SIZE.is_multiple_of(S::WRITE_SIZE as u32)
+            && RECORD_SIZE.is_multiple_of(S::READ_SIZE as u32)
+            && pages
+                .checked_mul(page_size)
+                .and_then(|size| size.checked_add(start))
+                .is_some_and(|end| end as usize <= flash.capacity());
+        if !valid_config {
+            return Err(EepromError::InvalidConfig);
+        }
+
+        Ok(Eeprom { flash, start, pages, active: 0, generation: 0, next: 0 })
+    }
+
+    /// Returns the latest value written for `key`, or `None` if it was never written.
+    pub fn read(&mut self, key: u16) -> Result<Option<u16>, EepromError<S::Error>>
+    {
+        if key == ERASED_KEY {
+            return Err(EepromError::InvalidKey);
+        }
+
+        for slot in (0..self.next).rev() {
+            if let Some((value, record_key)) = self.record(self.active, slot)? {
+                if record_key == key {
+                    return Ok(Some(value));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Writes `value` for `key`, swapping to the next page if the current one is full.
+    ///
+    /// Nothing is written if `key` already holds `value`. If programming fails part-way,
+    /// the partly written slot is skipped by the following writes.
+    pub fn write(&mut self, key: u16, value: u16) -> Result<(), EepromError<S::Error>>
+    {
+        if self.read(key)? == Some(value) {
+            return Ok(());
+        }
+
+        if self.next < self.slots() {
+            if let Err(error) = self.write_record(self.active, self.next, key, value) {
+                if let Ok(next) = self.first_free(self.active) {
+                    self.next = next;
+                }
+                return Err(error);
+            }
+            self.next += 1;
+            Ok(())
+        } else {
+            self.swap(key, value)
+        }
+    }
+
+    /// Erases all the pages and starts over with an empty store.
+    pub fn format(&mut self) -> Result<(), EepromError<S::Error>>
+    {
+        for page in 0..self.pages {
+            self.erase(page)?;
+        }
+        self.mark_receiving(0, 0)?;
+        self.mark_valid(0)?;
+
+        self.active = 0;
+        self.generation = 0;
+        self.next = 0;
+        Ok(())
+    }
+
+    /// Releases the underlying flash.
+    pub fn release(self) -> S
+    {
+        self.flash
+    }
+
+    /// Number of record slots in a page.
+    fn slots(&self) -> u32
+    {
+        (S::ERASE_SIZE as u32 - HEADER_SIZE) / RECORD_SIZE
+    }
+
+    fn page_offset(&self, page: u32) -> u32
+    {
+        self.start + page * S::ERASE_SIZE as u32
+    }
+
+    fn record_offset(&self, page: u32, slot: u32) -> u32
+    {
+        self.page_offset(page) + HEADER_SIZE + slot * RECORD_SIZE
+    }
+
+    fn read_word(&mut self, offset: u32) -> Result<[u8; 4], EepromError<S::Error>>
+    {
+        let mut bytes = [0; 4];
+        self.flash.read(offset, &mut bytes).map_err(EepromError::Flash)?;
+        Ok(bytes)
+    }
+
+    /// Reads the (value, key) record in `slot` of `page`, or `None` if the record was
+    /// not completely written.
+    fn record(&mut self, page: u32, slot: u32) -> Result<Option<(u16, u16)>, EepromError<S::Error>>
+    {
+        let offset = self.record_offset(page, slot);
+        if self.read_word(offset + COMPLETE_SLOT)? != COMPLETE_MARKER {
+            return Ok(None);
+        }
+
+        let bytes = self.read_word(offset)?;
+        Ok(Some((u16::from_le_bytes([bytes[0], bytes[1]]), u16::from_le_bytes([bytes[2], bytes[3]]))))
+    }
+
+    fn write_record(&mut self, page: u32, slot: u32, key: u16, value: u16) -> Result<(), EepromError<S::Error>>
+    {
+        let [v0, v1] = value.to_le_bytes();
+        let [k0, k1] = key.to_le_bytes();
+        let offset = self.record_offset(page, slot);
+        self.flash.write(offset, &[v0, v1, k0, k1]).map_err(EepromError::Flash)?;
+        self.flash.write(offset + COMPLETE_SLOT, &COMPLETE_MARKER).map_err(EepromError::Flash)
+    }
+
+    fn erase(&mut self, page: u32) -> Result<(), EepromError<S::Error>>
+    {
+        let offset = self.page_offset(page);
+        self.flash.erase(offset, offset + S::ERASE_SIZE as u32).map_err(EepromError::Flash)
+    }
+
+    /// Writes the generation of `page` and its complement into the RECEIVE slot.
+    fn mark_receiving(&mut self, page: u32, generation: u16) -> Result<(), EepromError<S::Error>>
+    {
+        let [g0, g1] = generation.to_le_bytes();
+        let offset = self.page_offset(page) + RECEIVE_SLOT;
+        self.flash.write(offset, &[g0, g1, !g0, !g1]).map_err(EepromError::Flash)
+    }
+
+    fn mark_valid(&mut self, page: u32) -> Result<(), EepromError<S::Error>>
+    {
+        let offset = self.page_offset(page) + VALID_SLOT;
+        self.flash.write(offset, &VALID_MARKER).map_err(EepromError::Flash)
+    }
+
+    /// Reads the header of `page`.
+    ///
+    /// # Returns
+    /// The generation and the VALID slot state, or `None` if the generation does not
+    /// match its complement (erased, torn or foreign page).
+    fn header(&mut self, page: u32) -> Result<Option<(u16, ValidMark)>, EepromError<S::Error>>
+    {
+        let offset = self.page_offset(page);
+        let receive = self.read_word(offset + RECEIVE_SLOT)?;
+        let generation = u16::from_le_bytes([receive[0], receive[1]]);
+        if !generation != u16::from_le_bytes([receive[2], receive[3]]) {
+            return Ok(None);
+        }
+
+        let mark = match self.read_word(offset + VALID_SLOT)? {
+            VALID_MARKER => ValidMark::Set,
+            [0xFF, 0xFF, 0xFF, 0xFF] => ValidMark::Erased,
+            _ => ValidMark::Partial,
+        };
+        Ok(Some((generation, mark)))
+    }
+
+    /// Returns the first erased record slot of `page`.
+    fn first_free(&mut self, page: u32) -> Result<u32, EepromError<S::Error>>
+    {
+        for slot in 0..self.slots() {
+            let offset = self.record_offset(page, slot);
+            if self.read_word(offset)? == [0xFF; 4] && self.read_word(offset + COMPLETE_SLOT)? == [0xFF; 4] {
+                return Ok(slot);
+            }
+        }
+        Ok(self.slots())
+    }
+
+    /// Finds the current page, finishing or rolling back an interrupted swap, and
+    /// erases the other pages that still carry a header.
+    fn recover(&mut self) -> Result<(), EepromError<S::Error>>
+    {
+        let mut newest: Option<(u32, u16, ValidMark)> = None;
+        let mut newest_valid: Option<(u32, u16)> = None;
+
+        for page in 0..self.pages {
+            let Some((generation, mark)) = self.header(page)? else {
+                continue;
+            };
+
+            match newest {
+                Some((_, other, _)) if other == generation => return Err(EepromError::Corrupted),
+                Some((_, other, _)) if is_newer(other, generation) => {}
+                _ => newest = Some((page, generation, mark)),
+            }
+            if mark == ValidMark::Set && newest_valid.is_none_or(|(_, other)| is_newer(generation, other)) {
+                newest_valid = Some((page, generation));
+            }
+        }
+
+        let (active, generation) = match newest {
+            None => return Err(EepromError::NotFormatted),
+            Some((page, generation, ValidMark::Set)) => (page, generation),
+            // swap cut while marking the new page valid: its copy is complete
+            Some((page, generation, ValidMark::Partial)) => {
+                self.mark_valid(page)?;
+                (page, generation)
+            }
+            // swap cut while filling the new page: the previous page is complete
+            Some((_, _, ValidMark::Erased)) => match newest_valid {
+                Some(previous) => previous,
+                None => return Err(EepromError::NotFormatted),
+            },
+        };
+
+        for page in 0..self.pages {
+            if page != active && self.read_word(self.page_offset(page) + RECEIVE_SLOT)? != [0xFF; 4] {
+                self.erase(page)?;
+            }
+        }
+
+        self.active = active;
+        self.generation = generation;
+        self.next = self.first_free(active)?;
+        Ok(())
+    }
+
+    /// Moves the latest values to the next page, together with the new record.
+    fn swap(&mut self, key: u16, value: u16) -> Result<(), EepromError<S::Error>>
+    {
+        let target = (self.active + 1) % self.pages;
+        let generation = self.generation.wrapping_add(1);
+
+        self.erase(target)?;
+        self.mark_receiving(target, generation)?;
+        self.write_record(target, 0, key, value)?;
+        let mut copied = 1;
+
+        for slot in (0..self.next).rev() {
+            let Some((record_value, record_key)) = self.record(self.active, slot)? else {
+                continue;
+            };
+            if record_key == key || self.holds(target, copied, record_key)? {
+                continue;
+            }
+            if copied == self.slots() {
+                // the current page is untouched, drop the unfinished copy
+                self.erase(target)?;
+                return Err(EepromError::Full);
+            }
+            self.write_record(target, copied, record_key, record_value)?;
+            copied += 1;
+        }
+
+        self.mark_valid(target)?;
+
+        // the new page is current from here on, even if erasing the old one fails
+        let old = self.active;
+        self.active = target;
+        self.generation = generation;
+        self.next = copied;
+
+        self.erase(old)
+    }
+
+    /// Checks whether the first `count` records of `page` contain `key`.
+    fn holds(&mut self, page: u32, count: u32, key: u16) -> Result<bool, EepromError<S::Error>>
+    {
+        for slot in 0..count {
+            if self.record(page, slot)?.is_some_and(|(_, record_key)| record_key == key) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use embedded_storage::nor_flash::{ErrorType, NorFlashError, NorFlashErrorKind, ReadNorFlash};
+
+    const PAGE: usize = 64;
+    const SIZE: usize = 4 * PAGE;
+
+    /// Record slots of a 64-byte page.
+    const SLOTS: u16 = 7;
+
+    /// How an erase cut by a power loss leaves the page.
+    #[derive(Clone, Copy, Debug)]
+    enum Torn
+    {
+        /// Only the first half is erased.
+        FirstHalf,
+        /// Only the second half is erased, the header is left as it was.
+        SecondHalf,
+        /// Every byte is partly erased.
+        LowNibbles,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct PowerLoss;
+
+    impl NorFlashError for PowerLoss
+    {
+        fn kind(&self) -> NorFlashErrorKind
+        {
+            NorFlashErrorKind::Other
+        }
+    }
+
+    /// NOR flash in RAM: writes only clear bits. Once `budget` operations (half-word
+    /// writes and page erases) are done, the next one is torn and fails, and so does
+    /// every later one until `power_up()`, unless the fault is `transient`.
+    struct RamFlash
+    {
+        mem:       [u8; SIZE],
+        budget:    Option<usize>,
+        torn:      Torn,
+        transient: bool,
+        dead:      bool,
+    }
+
+    impl RamFlash
+    {
+        fn new() -> Self
+        {
+            RamFlash { mem: [0xFF; SIZE], budget: None, torn: Torn::FirstHalf, transient: false, dead: false }
+        }
+
+        fn cut_after(mut self, budget: usize, torn: Torn) -> Self
+        {
+            self.budget = Some(budget);
+            self.torn = torn;
+            self
+        }
+
+        fn power_up(mut self) -> Self
+        {
+            self.budget = None;
+            self.dead = false;
+            self
+        }
+
+        /// Counts an operation and returns `true` if it is the one cut.
+        fn cut(&mut self) -> bool
+        {
+            match self.budget {
+                Some(0) => {
+                    self.budget = None;
+                    self.dead = !self.transient;
+                    true
+                }
+                Some(budget) => {
+                    self.budget = Some(budget - 1);
+                    false
+                }
+                None => false,
+            }
+        }
+    }
+
+    impl ErrorType for RamFlash
+    {
+        type Error = PowerLoss;
+    }
+
+    impl ReadNorFlash for RamFlash
+    {
+        const READ_SIZE: usize = 1;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), PowerLoss>
+        {
+            let offset = offset as usize;
+            bytes.copy_from_slice(&self.mem[offset..offset + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize
+        {
+            SIZE
+        }
+    }
+
+    impl NorFlash for RamFlash
+    {
+        const WRITE_SIZE: usize = 2;
+        const ERASE_SIZE: usize = PAGE;
+
+        fn erase(&mut self, from: u32, to: u32) -> Result<(), PowerLoss>
+        {
+            if self.dead {
+                return Err(PowerLoss);
+            }
+            let cut = self.cut();
+            let page = &mut self.mem[from as usize..to as usize];
+            if cut {
+                let half = page.len() / 2;
+                match self.torn {
+                    Torn::FirstHalf => page[..half].fill(0xFF),
+                    Torn::SecondHalf => page[half..].fill(0xFF),
+                    Torn::LowNibbles => page.iter_mut().for_each(|byte| *byte |= 0x0F),
+                }
+                return Err(PowerLoss);
+            }
+            page.fill(0xFF);
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), PowerLoss>
+        {
+            for (address, half_word) in (offset as usize..).step_by(2).zip(bytes.chunks(2)) {
+                if self.dead {
+                    return Err(PowerLoss);
+                }
+                if self.cut() {
+                    // only the low byte is programmed
+                    self.mem[address] &= half_word[0];
+                    return Err(PowerLoss);
+                }
+                self.mem[address] &= half_word[0];
+                self.mem[address + 1] &= half_word[1];
+            }
+            Ok(())
+        }
+    }
+
+    /// Checks that every complete record of the current page has a key accepted by `allowed`.
+    fn assert_keys(eeprom: &mut Eeprom<RamFlash>, allowed: impl Fn(u16) -> bool)
+    {
+        for slot in 0..eeprom.slots() {
+            if let Some((_, key)) = eeprom.record(eeprom.active, slot).unwrap() {
+                assert!(allowed(key), "unexpected key {key:#06x} in slot {slot}");
+            }
+        }
+    }
+
+    #[test]
+    fn values_round_trip()
+    {
+        let mut eeprom = Eeprom::new_formatted(RamFlash::new(), 0, 2).unwrap();
+        assert_eq!(eeprom.read(1), Ok(None));
+        eeprom.write(1, 0x1234).unwrap();
+        eeprom.write(2, 0xFFFF).unwrap();
+        eeprom.write(1, 0x5678).unwrap();
+        assert_eq!(eeprom.read(1), Ok(Some(0x5678)));
+        assert_eq!(eeprom.read(2), Ok(Some(0xFFFF)));
+        assert_eq!(eeprom.write(ERASED_KEY, 0), Err(EepromError::InvalidKey));
+
+        let mut eeprom = Eeprom::new(eeprom.release(), 0, 2).unwrap();
+        assert_eq!(eeprom.read(1), Ok(Some(0x5678)));
+        assert_eq!(eeprom.read(2), Ok(Some(0xFFFF)));
+    }
+
+    #[test]
+    fn invalid_configurations_are_rejected()
+    {
+        assert!(matches!(Eeprom::new(RamFlash::new(), 0, 1), Err(EepromError::InvalidConfig)));
+        assert!(matches!(Eeprom::new(RamFlash::new(), 32, 2), Err(EepromError::InvalidConfig)));
+        assert!(matches!(Eeprom::new(RamFlash::new(), 64, 4), Err(EepromError::InvalidConfig)));
+    }
+
+    #[test]
+    fn full_pages_swap_around_the_ring()
+    {
+        let mut eeprom = Eeprom::new_formatted(RamFlash::new(), 64, 3).unwrap();
+        let mut used = [false; 3];
+
+        for i in 0..200 {
+            eeprom.write(i % 5, i).unwrap();
+            used[eeprom.active as usize] = true;
+        }
+        assert_eq!(used, [true; 3]);
+
+        let mut eeprom = Eeprom::new(eeprom.release(), 64, 3).unwrap();
+        for key in 0..5 {
+            assert_eq!(eeprom.read(key), Ok(Some(195 + key)));
+        }
+    }
+
+    #[test]
+    fn too_many_keys_is_full()
+    {
+        let mut eeprom = Eeprom::new_formatted(RamFlash::new(), 0, 2).unwrap();
+        for key in 0..SLOTS {
+            eeprom.write(key, key).unwrap();
+        }
+        assert_eq!(eeprom.write(SLOTS, 0), Err(EepromError::Full));
+
+        // the existing keys still swap
+        eeprom.write(0, 100).unwrap();
+        let mut eeprom = Eeprom::new(eeprom.release(), 0, 2).unwrap();
+        assert_eq!(eeprom.read(0), Ok(Some(100)));
+        for key in 1..SLOTS {
+            assert_eq!(eeprom.read(key), Ok(Some(key)));
+        }
+        assert_eq!(eeprom.read(SLOTS), Ok(None));
+    }
+
+    #[test]
+    fn failed_write_skips_the_dirty_slot()
+    {
+        let mut flash = RamFlash::new();
+        flash.transient = true;
+        let mut eeprom = Eeprom::new_formatted(flash, 0, 2).unwrap();
+        eeprom.write(1, 10).unwrap();
+
+        // the value half-word is programmed, only the low byte of the key is
+        eeprom.flash.budget = Some(1);
+        assert_eq!(eeprom.write(2, 20), Err(EepromError::Flash(PowerLoss)));
+        assert_eq!(eeprom.read(0xFF02), Ok(None));
+
+        eeprom.write(2, 20).unwrap();
+        eeprom.write(3, 30).unwrap();
+        let mut eeprom = Eeprom::new(eeprom.release(), 0, 2).unwrap();
+        assert_eq!(eeprom.read(1), Ok(Some(10)));
+        assert_eq!(eeprom.read(2), Ok(Some(20)));
+        assert_eq!(eeprom.read(3), Ok(Some(30)));
+        assert_eq!(eeprom.read(0xFF02), Ok(None));
+        assert_keys(&mut eeprom, |key| (1..=3).contains(&key));
+    }
+
+    #[test]
+    fn unknown_range_is_left_untouched()
+    {
+        let mut flash = RamFlash::new();
+        assert!(matches!(Eeprom::new(&mut flash, 0, 2), Err(EepromError::NotFormatted)));
+
+        flash.mem.fill(0x5A);
+        assert!(matches!(Eeprom::new(&mut flash, 0, 2), Err(EepromError::NotFormatted)));
+        assert!(flash.mem.iter().all(|&byte| byte == 0x5A));
+    }
+
+    #[test]
+    fn foreign_data_needs_formatting()
+    {
+        let mut flash = RamFlash::new();
+        for page in [0, PAGE] {
+            flash.mem[page..page + 8].copy_from_slice(&[0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00]);
+        }
+        assert!(matches!(Eeprom::new(flash, 0, 2), Err(EepromError::Corrupted)));
+
+        let mut flash = RamFlash::new();
+        flash.mem.fill(0x00);
+        let mut eeprom = Eeprom::new_formatted(flash, 0, 2).unwrap();
+        assert_eq!(eeprom.read(1), Ok(None));
+    }
+
+    /// Cuts the power at each flash operation of a run of writes spanning several swaps,
+    /// for each way an erase can be torn. After power-up, every key must read either its
+    /// last written value or the value being written at the cut.
+    #[test]
+    fn power_loss_at_any_step_recovers()
+    {
+        const KEYS: u16 = 4;
+
+        for torn in [Torn::FirstHalf, Torn::SecondHalf, Torn::LowNibbles] {
+            for cut in 0..400 {
+                let mut eeprom = Eeprom::new_formatted(RamFlash::new(), 0, 2).unwrap();
+                let mut expected = [0; KEYS as usize];
+                for i in 0..30 {
+                    eeprom.write(i % KEYS, i).unwrap();
+                    expected[(i % KEYS) as usize] = i;
+                }
+
+                let mut eeprom = Eeprom::new(eeprom.release().cut_after(cut, torn), 0, 2).unwrap();
+                let mut pending = None;
+                for i in 30..90 {
+                    if eeprom.write(i % KEYS, i).is_err() {
+                        pending = Some((i % KEYS, i));
+                        break;
+                    }
+                    expected[(i % KEYS) as usize] = i;
+                }
+
+                let mut eeprom = Eeprom::new(eeprom.release().power_up(), 0, 2).unwrap();
+                for key in 0..KEYS {
+                    let value = eeprom.read(key).unwrap().unwrap();
+                    assert!(
+                        value == expected[key as usize] || pending == Some((key, value)),
+                        "{torn:?} cut at {cut}: key {key} reads {value}"
+                    );
+                }
+                assert_keys(&mut eeprom, |key| key < KEYS);
+
+                eeprom.write(1, 999).unwrap();
+                for i in 0..SLOTS {
+                    eeprom.write(0, i).unwrap();
+                }
+                assert_eq!(eeprom.read(1), Ok(Some(999)));
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/// STM32F103 device interrupts, numbered as in the vector table.
+///
+/// Used with the Cortex-M NVIC to enable or disable a vector.
+///
+/// # Example
+/// ```
+/// unsafe { cortex_m::peripheral::NVIC::unmask(Interrupt::EXTI0) };
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Interrupt
+{
+    WWDG = 0,
+    PVD = 1,
+    TAMPER = 2,
+    RTC = 3,
+    FLASH = 4,
+    RCC = 5,
+    EXTI0 = 6,
+    EXTI1 = 7,
+    EXTI2 = 8,
+    EXTI3 = 9,
+    EXTI4 = 10,
+    DMA1_CHANNEL1 = 11,
+    DMA1_CHANNEL2 = 12,
+    DMA1_CHANNEL3 = 13,
+    DMA1_CHANNEL4 = 14,
+    DMA1_CHANNEL5 = 15,
+    DMA1_CHANNEL6 = 16,
+    DMA1_CHANNEL7 = 17,
+    ADC1_2 = 18,
+    USB_HP_CAN_TX = 19,
+    USB_LP_CAN_RX0 = 20,
+    CAN_RX1 = 21,
+    CAN_SCE = 22,
+    EXTI9_5 = 23,
+    TIM1_BRK = 24,
+    TIM1_UP = 25,
+    TIM1_TRG_COM = 26,
+    TIM1_CC = 27,
+    TIM2 = 28,
+    TIM3 = 29,
+    TIM4 = 30,
+    I2C1_EV = 31,
+    I2C1_ER = 32,
+    I2C2_EV = 33,
+    I2C2_ER = 34,
+    SPI1 = 35,
+    SPI2 = 36,
+    USART1 = 37,
+    USART2 = 38,
+    USART3 = 39,
+    EXTI15_10 = 40,
+    RTCALARM = 41,
+    USBWAKEUP = 42,
+    TIM8_BRK = 43,
+    TIM8_UP = 44,
+    TIM8_TRG_COM = 45,
+    TIM8_CC = 46,
+    ADC3 = 47,
+    FSMC = 48,
+    SDIO = 49,
+    TIM5 = 50,
+    SPI3 = 51,
+    UART4 = 52,
+    UART5 = 53,
+    TIM6 = 54,
+    TIM7 = 55,
+    DMA2_CHANNEL1 = 56,
+    DMA2_CHANNEL2 = 57,
+    DMA2_CHANNEL3 = 58,
+    DMA2_CHANNEL4_5 = 59,
+}
+
+unsafe impl cortex_m::interrupt::InterruptNumber for Interrupt
+{
+    #[inline(always)]
+    fn number(self) -> u16
+    {
+        self as u16
+    }
+}
@@ -10,9 +10,33 @@
 //! - Peripherals abstraction
 //! - Runtime setup with reset handlers
 
+#[cfg(any(
+    all(feature = "low-density", any(feature = "medium-density", feature = "high-density", feature = "xl-density")),
+    all(feature = "medium-density", any(feature = "high-density", feature = "xl-density")),
+    all(feature = "high-density", feature = "xl-density"),
+))]
+compile_error!("only one device density feature can be enabled");
+
+#[cfg(any(
+    all(feature = "vfqfpn36", any(feature = "lqfp48", feature = "lqfp64", feature = "lqfp100", feature = "lqfp144")),
+    all(feature = "lqfp48", any(feature = "lqfp64", feature = "lqfp100", feature = "lqfp144")),
+    all(feature = "lqfp64", any(feature = "lqfp100", feature = "lqfp144")),
+    all(feature = "lqfp100", feature = "lqfp144"),
+))]
+compile_error!("only one package feature can be enabled");
+
+#[cfg(all(feature = "lqfp144", not(any(feature = "high-density", feature = "xl-density"))))]
+compile_error!("the lqfp144 package only exists for high-density and xl-density devices");
+
 /// Peripherals module containing abstractions for working with STM32F103 peripherals.
 pub mod peripherals;
 
+/// Interrupt module listing the device interrupts of the vector table, for use with the NVIC.
+pub mod interrupt;
+
+/// EEPROM emulation module, a wear-leveled key/value store on flash pages.
+pub mod eeprom;
+
 /// Runtime (rt) module for system initialization, reset handling, and interrupt vectors.
 /// This module includes the reset handler and vector table initialization.
 pub mod rt;
@@ -1,104 +1,115 @@
-use core::{marker::PhantomData, ops::Deref};
+use core::{marker::PhantomData, ops::{Deref, DerefMut}, sync::atomic::{AtomicBool, Ordering}};
 
 pub mod rcc;
 pub mod gpio;
+pub mod afio;
+pub mod exti;
+pub mod flash;
 
 use gpio::Gpio;
 
+/// RCC, turned into its clock configuration and bus handle with `constrain()`.
 pub struct Rcc<'a> {
   _marker: PhantomData<&'a rcc::Rcc>  
 }
 
-impl<'a> Deref for Rcc<'a> 
+/// AFIO, turned into its clocked handle with `constrain()`.
+pub struct Afio<'a> {
+  _marker: PhantomData<&'a afio::Afio>  
+}
+
+pub struct Exti<'a> {
+  _marker: PhantomData<&'a exti::Exti>  
+}
+
+impl<'a> Deref for Exti<'a> 
 {
-    type Target = rcc::Rcc;
+    type Target = exti::Exti;
     fn deref(&self) -> &Self::Target {
         unsafe {
-            & *(0x4002_1000 as *mut rcc::Rcc) 
+            & *(0x4001_0400 as *mut exti::Exti) 
         }
     }
 }
 
+/// GPIO port A, turned into its pins and port handle with `split()`.
 pub struct GpioA<'a> {
   _marker: PhantomData<&'a Gpio>  
 }
 
-impl<'a> Deref for GpioA<'a> 
-{
-    type Target = Gpio;
-    fn deref(&self) -> &Self::Target {
-        unsafe {
-            & *(0x4001_0800 as *mut Gpio) 
-        }
-    }
+/// GPIO port B, turned into its pins and port handle with `split()`.
+pub struct GpioB<'a> {
+  _marker: PhantomData<&'a Gpio>  
 }
 
-pub struct GpioB<'a> {
+/// GPIO port C, turned into its pins and port handle with `split()`.
+#[cfg(not(feature = "vfqfpn36"))]
+pub struct GpioC<'a> {
   _marker: PhantomData<&'a Gpio>  
 }
 
-impl<'a> Deref for GpioB<'a> 
-{
-    type Target = Gpio;
-    fn deref(&self) -> &Self::Target {
-        unsafe {
-            & *(0x4001_0C00 as *mut Gpio) 
-        }
-    }
+/// GPIO port D, turned into its pins and port handle with `split()`.
+pub struct GpioD<'a> {
+  _marker: PhantomData<&'a Gpio>  
 }
 
-pub struct GpioC<'a> {
+/// GPIO port E, turned into its pins and port handle with `split()`.
+#[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+pub struct GpioE<'a> {
   _marker: PhantomData<&'a Gpio>  
 }
 
-impl<'a> Deref for GpioC<'a> 
-{
-    type Target = Gpio;
-    fn deref(&self) -> &Self::Target {
-        unsafe {
-            & *(0x4001_1000 as *mut Gpio) 
-        }
-    }
+/// GPIO port F, turned into its pins and port handle with `split()`.
+#[cfg(feature = "lqfp144")]
+pub struct GpioF<'a> {
+  _marker: PhantomData<&'a Gpio>  
 }
 
-pub struct GpioD<'a> {
+/// GPIO port G, turned into its pins and port handle with `split()`.
+#[cfg(feature = "lqfp144")]
+pub struct GpioG<'a> {
   _marker: PhantomData<&'a Gpio>  
 }
 
-impl<'a> Deref for GpioD<'a> 
+pub struct Flash<'a> {
+  _marker: PhantomData<&'a flash::Flash>  
+}
+
+impl<'a> Deref for Flash<'a> 
 {
-    type Target = Gpio;
+    type Target = flash::Flash;
     fn deref(&self) -> &Self::Target {
         unsafe {
-            & *(0x4001_1400 as *mut Gpio) 
+            & *(0x4002_2000 as *mut flash::Flash) 
         }
     }
 }
 
-pub struct GpioE<'a> {
-  _marker: PhantomData<&'a Gpio>  
-}
-
-impl<'a> Deref for GpioE<'a> 
+impl<'a> DerefMut for Flash<'a> 
 {
-    type Target = Gpio;
-    fn deref(&self) -> &Self::Target {
+    fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe {
-            & *(0x4001_1800 as *mut Gpio) 
+            &mut *(0x4002_2000 as *mut flash::Flash) 
         }
     }
 }
 
+/// Set once the peripherals have been handed out by `Peripherals::take()`.
+static TAKEN: AtomicBool = AtomicBool::new(false);
+
 /// Struct containing all the peripherals for a given microcontroller, including:
-/// * GPIO ports (A-E)
+/// * GPIO ports (A-E by default, A-G on 144-pin packages, fewer on small packages)
 /// * Reset and Clock Control (RCC)
+/// * Alternate function I/O (AFIO)
+/// * External interrupts (EXTI)
+/// * Flash memory interface (FLASH)
 ///
 /// This struct provides easy access to the peripheral objects, allowing the user
 /// to configure and control the microcontroller's peripherals.
 ///
 /// # Example
 /// ```
-/// let peripherals = Peripherals::take();
+/// let peripherals = Peripherals::take().unwrap();
 /// let gpioa = peripherals.gpioa;
 /// let rcc = peripherals.rcc;
 /// ```
@@ -106,11 +117,20 @@ pub struct Peripherals<'a>
 {
     pub gpioa: GpioA<'a>,
     pub gpiob: GpioB<'a>,
+    #[cfg(not(feature = "vfqfpn36"))]
     pub gpioc: GpioC<'a>,
     pub gpiod: GpioD<'a>,
+    #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
     pub gpioe: GpioE<'a>,
-
-    pub rcc: Rcc<'a>
+    #[cfg(feature = "lqfp144")]
+    pub gpiof: GpioF<'a>,
+    #[cfg(feature = "lqfp144")]
+    pub gpiog: GpioG<'a>,
+
+    pub rcc: Rcc<'a>,
+    pub afio: Afio<'a>,
+    pub exti: Exti<'a>,
+    pub flash: Flash<'a>,
 }
 
 impl<'a> Peripherals<'a> 
@@ -119,24 +139,53 @@ impl<'a> Peripherals<'a>
     /// 
     /// This function returns a `Peripherals` instance, containing all the peripheral
     /// structs (GPIO ports, RCC, etc.), which can then be used to configure and control the hardware.
+    /// The peripherals are a singleton: only the first call returns `Some`, every
+    /// following call returns `None`.
     ///
     /// # Example
     /// ```
-    /// let peripherals = Peripherals::take();
+    /// let peripherals = Peripherals::take().unwrap();
     /// let gpioa = peripherals.gpioa;
     /// let rcc = peripherals.rcc;
     /// ```
-    pub fn take() -> Self 
+    pub fn take() -> Option<Self> 
     {
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            None
+        } else {
+            Some(unsafe { Self::steal() })
+        }
+    }
+
+    /// Returns the peripherals without checking whether they have already been taken.
+    ///
+    /// This also marks the peripherals as taken, so later calls to `take()` return `None`.
+    ///
+    /// # Safety
+    /// The caller must ensure that no other owner of the same peripherals exists, as two
+    /// handles can race on the read-modify-write sequences of the same register.
+    pub unsafe fn steal() -> Self 
+    {
+        TAKEN.store(true, Ordering::Release);
+
         Peripherals 
         {
             gpioa: GpioA { _marker: PhantomData},
             gpiob: GpioB { _marker: PhantomData},
+            #[cfg(not(feature = "vfqfpn36"))]
             gpioc: GpioC { _marker: PhantomData},
             gpiod: GpioD { _marker: PhantomData},
+            #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
             gpioe: GpioE { _marker: PhantomData},
+            #[cfg(feature = "lqfp144")]
+            gpiof: GpioF { _marker: PhantomData},
+            #[cfg(feature = "lqfp144")]
+            gpiog: GpioG { _marker: PhantomData},
 
             rcc: Rcc {_marker: PhantomData},
+            afio: Afio {_marker: PhantomData},
+            exti: Exti {_marker: PhantomData},
+            flash: Flash {_marker: PhantomData},
         }
     }
 }
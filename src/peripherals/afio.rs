@@ -0,0 +1,622 @@
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use volatile_register::RW;
+
+use super::gpio::pin::{AlternateInputState, AlternateOpenDrain, AlternatePushPull, AlternateState, Debugger, Disabled, PinConfig};
+use super::gpio::{PA10, PA11, PA12, PA13, PA14, PA15, PA2, PA3, PA5, PA6, PA7, PA9};
+use super::gpio::{PB3, PB4, PB5, PB6, PB7};
+#[cfg(not(feature = "vfqfpn36"))]
+use super::gpio::{PB10, PB11, PB8, PB9};
+#[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48")))]
+use super::gpio::{PC10, PC11};
+#[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+use super::gpio::{PD0, PD1, PD5, PD6, PD8, PD9};
+
+/// SWJ_CFG field of MAPR. It is write-only and reads back undefined.
+const SWJ_CFG_MASK: u32 = 0b111 << 24;
+
+/// Last value written to SWJ_CFG, written again on every MAPR update.
+static SWJ_CFG: AtomicU32 = AtomicU32::new(SwjConfig::Full as u32);
+
+#[allow(dead_code)]
+pub struct Afio
+{
+    pub evcr:   RW<u32>,
+    pub mapr:   RW<u32>,
+    pub exticr: [RW<u32>; 4],
+    _reserved:  u32,
+    pub mapr2:  RW<u32>,
+}
+
+/// Serial wire JTAG configuration (SWJ_CFG).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwjConfig
+{
+    /// Full SWJ (JTAG-DP + SW-DP), reset state
+    Full = 0b000,
+    /// Full SWJ without NJTRST, PB4 is released
+    FullNoNjtrst = 0b001,
+    /// JTAG-DP disabled and SW-DP enabled, PA15, PB3 and PB4 are released
+    SwdOnly = 0b010,
+    /// JTAG-DP and SW-DP disabled, PA13, PA14, PA15, PB3 and PB4 are released
+    Disabled = 0b100,
+}
+
+/// A remap setting of one MAPR/MAPR2 field.
+pub trait Remap: Copy
+{
+    /// `true` if the field lives in MAPR2 (XL-density devices) rather than MAPR.
+    const MAPR2: bool;
+    /// Position of the field in the register.
+    const OFFSET: u8;
+    /// Mask of the field, before shifting.
+    const MASK: u32;
+
+    /// Value of the field for this setting, before shifting.
+    fn bits(self) -> u32;
+}
+
+/// A remap setting that can be applied on its own with `Parts::remap()`.
+///
+/// Fields with `Pins` sets are left out: they are only written by `remap_pins()`, so
+/// no remap can be changed under the pins a driver holds.
+pub trait FreeRemap: Remap {}
+
+impl FreeRemap for Tim1Remap {}
+impl FreeRemap for Tim2Remap {}
+impl FreeRemap for Tim3Remap {}
+impl FreeRemap for Tim4Remap {}
+impl FreeRemap for Pd01Remap {}
+impl FreeRemap for Tim5Ch4Remap {}
+impl FreeRemap for Adc1EtrgInjRemap {}
+impl FreeRemap for Adc1EtrgRegRemap {}
+impl FreeRemap for Adc2EtrgInjRemap {}
+impl FreeRemap for Adc2EtrgRegRemap {}
+impl FreeRemap for Tim9Remap {}
+impl FreeRemap for Tim10Remap {}
+impl FreeRemap for Tim11Remap {}
+impl FreeRemap for Tim13Remap {}
+impl FreeRemap for Tim14Remap {}
+impl FreeRemap for FsmcNadvRemap {}
+
+/// A set of pins usable by a peripheral, together with the remap setting they require.
+///
+/// Only valid pin sets implement it. Peripheral drivers take their pins as
+/// `RemappedPins`, obtained from `Parts::remap_pins()`, so the remap is always applied.
+pub trait Pins<REMAP: Remap>
+{
+    const REMAP: REMAP;
+}
+
+macro_rules! remap {
+    ($(#[$attr: meta])* $Remap: ident, $mapr2: literal, $offset: literal, $mask: literal, {
+        $($(#[$vattr: meta])* $Variant: ident = $bits: literal),+ $(,)?
+    }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $Remap
+        {
+            $($(#[$vattr])* $Variant = $bits),+
+        }
+
+        impl Remap for $Remap
+        {
+            const MAPR2: bool = $mapr2;
+            const OFFSET: u8 = $offset;
+            const MASK: u32 = $mask;
+
+            fn bits(self) -> u32
+            {
+                self as u32
+            }
+        }
+    }
+}
+
+remap!(
+    /// SPI1_REMAP
+    Spi1Remap, false, 0, 0b1, {
+    /// NSS/PA4, SCK/PA5, MISO/PA6, MOSI/PA7
+    NoRemap = 0b0,
+    /// NSS/PA15, SCK/PB3, MISO/PB4, MOSI/PB5
+    Remap = 0b1,
+});
+
+remap!(
+    /// I2C1_REMAP
+    I2c1Remap, false, 1, 0b1, {
+    /// SCL/PB6, SDA/PB7
+    NoRemap = 0b0,
+    /// SCL/PB8, SDA/PB9
+    Remap = 0b1,
+});
+
+remap!(
+    /// USART1_REMAP
+    Usart1Remap, false, 2, 0b1, {
+    /// TX/PA9, RX/PA10
+    NoRemap = 0b0,
+    /// TX/PB6, RX/PB7
+    Remap = 0b1,
+});
+
+remap!(
+    /// USART2_REMAP
+    Usart2Remap, false, 3, 0b1, {
+    /// TX/PA2, RX/PA3
+    NoRemap = 0b0,
+    /// TX/PD5, RX/PD6
+    Remap = 0b1,
+});
+
+remap!(
+    /// USART3_REMAP
+    Usart3Remap, false, 4, 0b11, {
+    /// TX/PB10, RX/PB11
+    NoRemap = 0b00,
+    /// TX/PC10, RX/PC11
+    PartialRemap = 0b01,
+    /// TX/PD8, RX/PD9
+    FullRemap = 0b11,
+});
+
+remap!(
+    /// TIM1_REMAP
+    Tim1Remap, false, 6, 0b11, {
+    /// ETR/PA12, CH1/PA8, CH2/PA9, CH3/PA10, CH4/PA11, BKIN/PB12, CH1N/PB13, CH2N/PB14, CH3N/PB15
+    NoRemap = 0b00,
+    /// ETR/PA12, CH1/PA8, CH2/PA9, CH3/PA10, CH4/PA11, BKIN/PA6, CH1N/PA7, CH2N/PB0, CH3N/PB1
+    PartialRemap = 0b01,
+    /// ETR/PE7, CH1/PE9, CH2/PE11, CH3/PE13, CH4/PE14, BKIN/PE15, CH1N/PE8, CH2N/PE10, CH3N/PE12
+    FullRemap = 0b11,
+});
+
+remap!(
+    /// TIM2_REMAP
+    Tim2Remap, false, 8, 0b11, {
+    /// CH1/ETR/PA0, CH2/PA1, CH3/PA2, CH4/PA3
+    NoRemap = 0b00,
+    /// CH1/ETR/PA15, CH2/PB3, CH3/PA2, CH4/PA3
+    PartialRemap1 = 0b01,
+    /// CH1/ETR/PA0, CH2/PA1, CH3/PB10, CH4/PB11
+    PartialRemap2 = 0b10,
+    /// CH1/ETR/PA15, CH2/PB3, CH3/PB10, CH4/PB11
+    FullRemap = 0b11,
+});
+
+remap!(
+    /// TIM3_REMAP
+    Tim3Remap, false, 10, 0b11, {
+    /// CH1/PA6, CH2/PA7, CH3/PB0, CH4/PB1
+    NoRemap = 0b00,
+    /// CH1/PB4, CH2/PB5, CH3/PB0, CH4/PB1
+    PartialRemap = 0b10,
+    /// CH1/PC6, CH2/PC7, CH3/PC8, CH4/PC9
+    FullRemap = 0b11,
+});
+
+remap!(
+    /// TIM4_REMAP
+    Tim4Remap, false, 12, 0b1, {
+    /// CH1/PB6, CH2/PB7, CH3/PB8, CH4/PB9
+    NoRemap = 0b0,
+    /// CH1/PD12, CH2/PD13, CH3/PD14, CH4/PD15
+    Remap = 0b1,
+});
+
+remap!(
+    /// CAN_REMAP
+    CanRemap, false, 13, 0b11, {
+    /// RX/PA11, TX/PA12
+    NoRemap = 0b00,
+    /// RX/PB8, TX/PB9
+    Remap2 = 0b10,
+    /// RX/PD0, TX/PD1
+    Remap3 = 0b11,
+});
+
+remap!(
+    /// PD01_REMAP
+    Pd01Remap, false, 15, 0b1, {
+    /// PD0/PD1 are not available, OSC_IN/OSC_OUT stay on them
+    NoRemap = 0b0,
+    /// OSC_IN/OSC_OUT are mapped on PD0/PD1 so they can be used as GPIO
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM5CH4_IREMAP
+    Tim5Ch4Remap, false, 16, 0b1, {
+    /// TIM5_CH4 is connected to PA3
+    NoRemap = 0b0,
+    /// LSI is connected to TIM5_CH4 for calibration
+    Remap = 0b1,
+});
+
+remap!(
+    /// ADC1_ETRGINJ_REMAP
+    Adc1EtrgInjRemap, false, 17, 0b1, {
+    /// ADC1 injected external trigger connected to EXTI15
+    NoRemap = 0b0,
+    /// ADC1 injected external trigger connected to TIM8_CH4
+    Remap = 0b1,
+});
+
+remap!(
+    /// ADC1_ETRGREG_REMAP
+    Adc1EtrgRegRemap, false, 18, 0b1, {
+    /// ADC1 regular external trigger connected to EXTI11
+    NoRemap = 0b0,
+    /// ADC1 regular external trigger connected to TIM8_TRGO
+    Remap = 0b1,
+});
+
+remap!(
+    /// ADC2_ETRGINJ_REMAP
+    Adc2EtrgInjRemap, false, 19, 0b1, {
+    /// ADC2 injected external trigger connected to EXTI15
+    NoRemap = 0b0,
+    /// ADC2 injected external trigger connected to TIM8_CH4
+    Remap = 0b1,
+});
+
+remap!(
+    /// ADC2_ETRGREG_REMAP
+    Adc2EtrgRegRemap, false, 20, 0b1, {
+    /// ADC2 regular external trigger connected to EXTI11
+    NoRemap = 0b0,
+    /// ADC2 regular external trigger connected to TIM8_TRGO
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM9_REMAP (MAPR2, XL-density devices)
+    Tim9Remap, true, 5, 0b1, {
+    /// CH1/PA2, CH2/PA3
+    NoRemap = 0b0,
+    /// CH1/PE5, CH2/PE6
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM10_REMAP (MAPR2, XL-density devices)
+    Tim10Remap, true, 6, 0b1, {
+    /// CH1/PB8
+    NoRemap = 0b0,
+    /// CH1/PF6
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM11_REMAP (MAPR2, XL-density devices)
+    Tim11Remap, true, 7, 0b1, {
+    /// CH1/PB9
+    NoRemap = 0b0,
+    /// CH1/PF7
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM13_REMAP (MAPR2, XL-density devices)
+    Tim13Remap, true, 8, 0b1, {
+    /// CH1/PA6
+    NoRemap = 0b0,
+    /// CH1/PF8
+    Remap = 0b1,
+});
+
+remap!(
+    /// TIM14_REMAP (MAPR2, XL-density devices)
+    Tim14Remap, true, 9, 0b1, {
+    /// CH1/PA7
+    NoRemap = 0b0,
+    /// CH1/PF9
+    Remap = 0b1,
+});
+
+remap!(
+    /// FSMC_NADV (MAPR2, XL-density devices)
+    FsmcNadvRemap, true, 10, 0b1, {
+    /// The NADV signal is connected to the output
+    Connected = 0b0,
+    /// The NADV signal is not connected, the pin can be used by another peripheral
+    Disconnected = 0b1,
+});
+
+impl<'a> super::Afio<'a>
+{
+    /// Enables the AFIO clock and resets AFIO, and returns the handle to its registers.
+    ///
+    /// This consumes the AFIO wrapper, so AFIO is only reset once, before any remap,
+    /// EXTI routing or debug pin release is made through the returned `Parts`.
+    ///
+    /// # Arguments
+    /// * `rcc` - Reference to the `Rcc` used to enable and reset AFIO.
+    ///
+    /// # Example
+    /// ```
+    /// let afio = dp.afio.constrain(rcc.bus);
+    /// afio.remap(Tim2Remap::PartialRemap2);
+    /// ```
+    pub fn constrain(self, rcc: &super::rcc::Rcc) -> Parts
+    {
+        rcc.enable_afio();
+        rcc.reset_afio();
+        SWJ_CFG.store(SwjConfig::Full as u32, Ordering::Relaxed);
+
+        Parts { _marker: PhantomData }
+    }
+}
+
+impl Afio
+{
+    /// Returns the AFIO register block.
+    #[inline(always)]
+    fn get() -> &'static Afio
+    {
+        unsafe {
+            & *(0x4001_0000 as *const Afio)
+        }
+    }
+}
+
+/// AFIO with its clock enabled, returned by `constrain()`.
+pub struct Parts
+{
+    _marker: PhantomData<Afio>,
+}
+
+impl Parts
+{
+    /// Returns the AFIO register block.
+    #[inline(always)]
+    pub(crate) fn regs(&self) -> &'static Afio
+    {
+        Afio::get()
+    }
+
+    /// Applies a remap setting to its MAPR/MAPR2 field, leaving the other fields unchanged.
+    ///
+    /// The SPI1, I2C1, USART and CAN remaps are applied with `remap_pins()` instead.
+    ///
+    /// # Example
+    /// ```
+    /// let afio = dp.afio.constrain(rcc.bus);
+    /// afio.remap(Tim2Remap::PartialRemap2);
+    /// ```
+    pub fn remap<R: FreeRemap>(&self, remap: R)
+    {
+        self.write_remap(remap);
+    }
+
+    /// Writes a remap setting to its MAPR/MAPR2 field, leaving the other fields unchanged.
+    fn write_remap<R: Remap>(&self, remap: R)
+    {
+        let mask = R::MASK << R::OFFSET;
+        let bits = remap.bits() << R::OFFSET;
+        let afio = self.regs();
+
+        unsafe {
+            if R::MAPR2 {
+                afio.mapr2.modify(|r| r & !mask | bits);
+            } else {
+                // SWJ_CFG reads back undefined, write the last configured value instead
+                let swj_cfg = SWJ_CFG.load(Ordering::Relaxed) << 24;
+                afio.mapr.modify(|r| r & !(mask | SWJ_CFG_MASK) | bits | swj_cfg);
+            }
+        }
+    }
+
+    /// Writes SWJ_CFG and remembers it for the following MAPR updates.
+    ///
+    /// Taking `&self` on the constrained handle guarantees the AFIO clock is on, so the
+    /// write is never dropped while the pins are handed out as released.
+    fn set_swj_config(&self, config: SwjConfig)
+    {
+        SWJ_CFG.store(config as u32, Ordering::Relaxed);
+
+        unsafe {
+            self.regs().mapr.modify(|r| r & !SWJ_CFG_MASK | ((config as u32) << 24));
+        }
+    }
+
+    /// Releases NJTRST and returns PB4 for general-purpose use (`SwjConfig::FullNoNjtrst`).
+    /// 
+    /// JTAG and SWD debugging stay available without the reset line.
+    ///
+    /// # Example
+    /// ```
+    /// let pb4 = afio.release_njtrst(gpiob.p4);
+    /// ```
+    pub fn release_njtrst(&self, _pb4: PB4<Debugger>) -> PB4<Disabled>
+    {
+        self.set_swj_config(SwjConfig::FullNoNjtrst);
+        PinConfig::new()
+    }
+
+    /// Disables JTAG, keeping SWD, and returns PA15, PB3 and PB4 (`SwjConfig::SwdOnly`).
+    ///
+    /// # Example
+    /// ```
+    /// let afio = dp.afio.constrain(rcc.bus);
+    /// let (pa15, pb3, pb4) = afio.disable_jtag(gpioa.p15, gpiob.p3, gpiob.p4);
+    /// let led = pa15.into_output_pushpull(PinSpeed::Speed2Hz);
+    /// ```
+    pub fn disable_jtag(&self, _pa15: PA15<Debugger>, _pb3: PB3<Debugger>, _pb4: PB4<Debugger>)
+        -> (PA15<Disabled>, PB3<Disabled>, PB4<Disabled>)
+    {
+        self.set_swj_config(SwjConfig::SwdOnly);
+        (PinConfig::new(), PinConfig::new(), PinConfig::new())
+    }
+
+    /// Disables both JTAG and SWD and returns all the debug pins (`SwjConfig::Disabled`).
+    /// 
+    /// The debugger can no longer attach to the running firmware; it has to connect
+    /// under reset to reprogram the device.
+    ///
+    /// # Example
+    /// ```
+    /// let (pa13, pa14, pa15, pb3, pb4) = afio.disable_swj(gpioa.p13, gpioa.p14, gpioa.p15, gpiob.p3, gpiob.p4);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn disable_swj(&self, _pa13: PA13<Debugger>, _pa14: PA14<Debugger>, _pa15: PA15<Debugger>, _pb3: PB3<Debugger>, _pb4: PB4<Debugger>)
+        -> (PA13<Disabled>, PA14<Disabled>, PA15<Disabled>, PB3<Disabled>, PB4<Disabled>)
+    {
+        self.set_swj_config(SwjConfig::Disabled);
+        (PinConfig::new(), PinConfig::new(), PinConfig::new(), PinConfig::new(), PinConfig::new())
+    }
+
+    /// Applies the remap setting required by a set of pins and takes the pins.
+    /// 
+    /// Peripheral drivers receive the returned `RemappedPins`, so the mapping always
+    /// matches the pins the driver was given.
+    ///
+    /// # Example
+    /// ```
+    /// let tx = gpiob.p6.into_alternate_pushpull(PinSpeed::Speed50Hz);
+    /// let rx = gpiob.p7.into_input_floating();
+    /// let pins = afio.remap_pins::<Usart1Remap, _>((tx, rx));
+    /// ```
+    pub fn remap_pins<R: Remap, PINS: Pins<R>>(&self, pins: PINS) -> RemappedPins<R, PINS>
+    {
+        self.write_remap(PINS::REMAP);
+        RemappedPins { pins, _remap: PhantomData }
+    }
+}
+
+/// A set of pins whose remap setting has been applied by `Parts::remap_pins()`.
+pub struct RemappedPins<R: Remap, PINS: Pins<R>>
+{
+    pins: PINS,
+    _remap: PhantomData<R>,
+}
+
+impl<R: Remap, PINS: Pins<R>> RemappedPins<R, PINS>
+{
+    /// Returns the pins. The remap setting is left as it is.
+    pub fn release(self) -> PINS
+    {
+        self.pins
+    }
+}
+
+/// The Cortex-M EVENTOUT signal routed to a pin through EVCR.
+///
+/// Each `SEV` instruction executed by the core then emits a one-cycle pulse on the pin.
+///
+/// # Example
+/// ```
+/// let pin = gpioa.p8.into_alternate_pushpull(PinSpeed::Speed50Hz);
+/// let event_out = afio.event_out(pin);
+/// event_out.send_event();
+/// ```
+pub struct EventOut<const P: char, const N: u8, STATE>
+{
+    pin: PinConfig<P, N, STATE>,
+}
+
+impl Parts
+{
+    /// Routes EVENTOUT to `pin` and enables it (EVCR PORT, PIN and EVOE).
+    /// 
+    /// Only ports A to E can carry EVENTOUT; a pin of another port does not compile.
+    pub fn event_out<const P: char, const N: u8, STATE: AlternateState>(&self, pin: PinConfig<P, N, STATE>) -> EventOut<P, N, STATE>
+    {
+        const { assert!(P >= 'A' && P <= 'E', "EVENTOUT is only available on ports A to E") };
+        let port = (P as u32) - ('A' as u32);
+
+        unsafe {
+            self.regs().evcr.write((1 << 7) | (port << 4) | N as u32);
+        }
+
+        EventOut { pin }
+    }
+}
+
+impl<const P: char, const N: u8, STATE> EventOut<P, N, STATE>
+{
+    /// Executes `SEV`, which pulses the EVENTOUT pin and wakes up cores waiting in `WFE`.
+    #[inline(always)]
+    pub fn send_event(&self)
+    {
+        cortex_m::asm::sev();
+    }
+
+    /// Disables EVENTOUT (clears EVOE) and returns the pin.
+    pub fn release(self, afio: &Parts) -> PinConfig<P, N, STATE>
+    {
+        unsafe {
+            afio.regs().evcr.modify(|r| r & !(1 << 7));
+        }
+
+        self.pin
+    }
+}
+
+macro_rules! pins {
+    ($Remap: ident, [$($(#[$attr: meta])* ($($pin: ty),+) => $Variant: ident),+ $(,)?]) => {
+        $(
+            $(#[$attr])*
+            impl<RX: AlternateInputState> Pins<$Remap> for ($($pin),+)
+            {
+                const REMAP: $Remap = $Remap::$Variant;
+            }
+        )+
+    }
+}
+
+// Pin sets are only implemented on the packages that bond their pins out: PB8..PB11
+// are missing on the 36-pin package, PC10/PC11 on the 36/48-pin packages and PD2..PD15
+// on the 36/48/64-pin packages. On those packages PD0/PD1 are OSC_IN/OSC_OUT, so CAN
+// remap 3 is limited to the 100- and 144-pin packages as well.
+
+// (TX, RX)
+pins!(Usart1Remap, [
+    (PA9<AlternatePushPull>, PA10<RX>) => NoRemap,
+    (PB6<AlternatePushPull>, PB7<RX>) => Remap,
+]);
+
+// (TX, RX)
+pins!(Usart2Remap, [
+    (PA2<AlternatePushPull>, PA3<RX>) => NoRemap,
+    #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+    (PD5<AlternatePushPull>, PD6<RX>) => Remap,
+]);
+
+// (TX, RX)
+pins!(Usart3Remap, [
+    #[cfg(not(feature = "vfqfpn36"))]
+    (PB10<AlternatePushPull>, PB11<RX>) => NoRemap,
+    #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48")))]
+    (PC10<AlternatePushPull>, PC11<RX>) => PartialRemap,
+    #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+    (PD8<AlternatePushPull>, PD9<RX>) => FullRemap,
+]);
+
+// (SCK, MISO, MOSI)
+pins!(Spi1Remap, [
+    (PA5<AlternatePushPull>, PA6<RX>, PA7<AlternatePushPull>) => NoRemap,
+    (PB3<AlternatePushPull>, PB4<RX>, PB5<AlternatePushPull>) => Remap,
+]);
+
+// (TX, RX)
+pins!(CanRemap, [
+    (PA12<AlternatePushPull>, PA11<RX>) => NoRemap,
+    #[cfg(not(feature = "vfqfpn36"))]
+    (PB9<AlternatePushPull>, PB8<RX>) => Remap2,
+    #[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+    (PD1<AlternatePushPull>, PD0<RX>) => Remap3,
+]);
+
+// (SCL, SDA)
+impl Pins<I2c1Remap> for (PB6<AlternateOpenDrain>, PB7<AlternateOpenDrain>)
+{
+    const REMAP: I2c1Remap = I2c1Remap::NoRemap;
+}
+
+#[cfg(not(feature = "vfqfpn36"))]
+impl Pins<I2c1Remap> for (PB8<AlternateOpenDrain>, PB9<AlternateOpenDrain>)
+{
+    const REMAP: I2c1Remap = I2c1Remap::Remap;
+}
@@ -0,0 +1,147 @@
+use volatile_register::RW;
+
+use crate::interrupt::Interrupt;
+
+use super::afio;
+use super::gpio::pin::{DigitalInputState, PinConfig};
+
+pub struct Exti
+{
+    pub imr:   RW<u32>,
+    pub emr:   RW<u32>,
+    pub rtsr:  RW<u32>,
+    pub ftsr:  RW<u32>,
+    pub swier: RW<u32>,
+    pub pr:    RW<u32>,
+}
+
+impl Exti
+{
+    /// Returns the EXTI register block, for use from interrupt handlers.
+    #[inline(always)]
+    fn get() -> &'static Exti
+    {
+        unsafe {
+            & *(0x4001_0400 as *const Exti)
+        }
+    }
+}
+
+/// Signal edge that triggers an EXTI line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge
+{
+    Rising,
+    Falling,
+    RisingFalling,
+}
+
+/// External interrupt configuration of an input pin.
+///
+/// Pin `N` of any port drives EXTI line `N`; only one port can be routed to a line at a time.
+/// Analog inputs are left out, their Schmitt trigger is disconnected and never raises an edge.
+///
+/// # Example
+/// ```
+/// let afio = dp.afio.constrain(rcc.bus);
+/// let mut button = gpioa.p0.into_input_pullup();
+/// button.make_interrupt_source(&afio);
+/// button.trigger_on_edge(&dp.exti, Edge::Falling);
+/// button.enable_interrupt(&dp.exti);
+/// unsafe { cortex_m::peripheral::NVIC::unmask(button.interrupt()) };
+///
+/// // in the EXTI0 handler
+/// if button.check_interrupt() {
+///     button.clear_interrupt_pending_bit();
+/// }
+/// ```
+pub trait ExtiPin
+{
+    /// Routes the pin to its EXTI line through AFIO EXTICRx.
+    fn make_interrupt_source(&mut self, afio: &afio::Parts);
+
+    /// Selects the edge(s) that trigger the line.
+    fn trigger_on_edge(&mut self, exti: &Exti, edge: Edge);
+
+    /// Unmasks the interrupt request of the line.
+    fn enable_interrupt(&mut self, exti: &Exti);
+
+    /// Masks the interrupt request of the line.
+    fn disable_interrupt(&mut self, exti: &Exti);
+
+    /// Checks whether the line has a pending interrupt.
+    fn check_interrupt(&self) -> bool;
+
+    /// Clears the pending bit of the line.
+    fn clear_interrupt_pending_bit(&mut self);
+
+    /// Returns the NVIC vector that serves the line (EXTI0..EXTI4, EXTI9_5 or EXTI15_10).
+    fn interrupt(&self) -> Interrupt;
+}
+
+impl<const P: char, const N: u8, STATE: DigitalInputState> ExtiPin for PinConfig<P, N, STATE>
+{
+    fn make_interrupt_source(&mut self, afio: &afio::Parts)
+    {
+        let port = (P as u32) - ('A' as u32);
+        let offset = (N % 4) * 4;
+
+        unsafe {
+            afio.regs().exticr[(N / 4) as usize].modify(|r| r & !(0b1111 << offset) | (port << offset));
+        }
+    }
+
+    fn trigger_on_edge(&mut self, exti: &Exti, edge: Edge)
+    {
+        let (rising, falling) = match edge {
+            Edge::Rising => (true, false),
+            Edge::Falling => (false, true),
+            Edge::RisingFalling => (true, true),
+        };
+
+        unsafe {
+            exti.rtsr.modify(|r| if rising { r | (1 << N) } else { r & !(1 << N) });
+            exti.ftsr.modify(|r| if falling { r | (1 << N) } else { r & !(1 << N) });
+        }
+    }
+
+    fn enable_interrupt(&mut self, exti: &Exti)
+    {
+        unsafe {
+            exti.imr.modify(|r| r | (1 << N));
+        }
+    }
+
+    fn disable_interrupt(&mut self, exti: &Exti)
+    {
+        unsafe {
+            exti.imr.modify(|r| r & !(1 << N));
+        }
+    }
+
+    fn check_interrupt(&self) -> bool
+    {
+        (Exti::get().pr.read() & (1 << N)) != 0
+    }
+
+    fn clear_interrupt_pending_bit(&mut self)
+    {
+        // PR bits are cleared by writing 1, writing 0 has no effect
+        unsafe {
+            Exti::get().pr.write(1 << N);
+        }
+    }
+
+    fn interrupt(&self) -> Interrupt
+    {
+        match N {
+            0 => Interrupt::EXTI0,
+            1 => Interrupt::EXTI1,
+            2 => Interrupt::EXTI2,
+            3 => Interrupt::EXTI3,
+            4 => Interrupt::EXTI4,
+            5..=9 => Interrupt::EXTI9_5,
+            _ => Interrupt::EXTI15_10,
+        }
+    }
+}
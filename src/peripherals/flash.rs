@@ -0,0 +1,719 @@
+use core::ptr;
+
+use embedded_storage::nor_flash::{ErrorType, NorFlash, NorFlashError, NorFlashErrorKind, ReadNorFlash};
+use volatile_register::{RO, RW};
+
+#[allow(dead_code)]
+pub struct Flash
+{
+    acr:     RW<u32>,
+    keyr:    RW<u32>,
+    optkeyr: RW<u32>,
+    sr:      RW<u32>,
+    cr:      RW<u32>,
+    ar:      RW<u32>,
+    _reserved: u32,
+    obr:     RO<u32>,
+    wrpr:    RO<u32>,
+    #[cfg(feature = "xl-density")]
+    _reserved1: [u32; 8],
+    #[cfg(feature = "xl-density")]
+    keyr2:   RW<u32>,
+    #[cfg(feature = "xl-density")]
+    _reserved2: u32,
+    #[cfg(feature = "xl-density")]
+    sr2:     RW<u32>,
+    #[cfg(feature = "xl-density")]
+    cr2:     RW<u32>,
+    #[cfg(feature = "xl-density")]
+    ar2:     RW<u32>,
+}
+
+/// Start address of the main flash memory.
+pub const FLASH_START: u32 = 0x0800_0000;
+
+/// Size of an erase page: 2 Kbytes on high and XL-density devices, 1 Kbyte otherwise.
+#[cfg(any(feature = "high-density", feature = "xl-density"))]
+pub const PAGE_SIZE: u32 = 2048;
+#[cfg(not(any(feature = "high-density", feature = "xl-density")))]
+pub const PAGE_SIZE: u32 = 1024;
+
+/// Offset of the second flash bank on XL-density devices.
+#[cfg(feature = "xl-density")]
+const BANK2_OFFSET: u32 = 512 * 1024;
+
+/// Flash size register (F_SIZE), in Kbytes.
+const F_SIZE: *const u16 = 0x1FFF_F7E0 as *const u16;
+
+const KEY1: u32 = 0x4567_0123;
+const KEY2: u32 = 0xCDEF_89AB;
+
+const SR_BSY: u32 = 1 << 0;
+const SR_PGERR: u32 = 1 << 2;
+const SR_WRPRTERR: u32 = 1 << 4;
+const SR_EOP: u32 = 1 << 5;
+
+const CR_PG: u32 = 1 << 0;
+const CR_PER: u32 = 1 << 1;
+const CR_MER: u32 = 1 << 2;
+const CR_STRT: u32 = 1 << 6;
+const CR_LOCK: u32 = 1 << 7;
+const CR_OPTPG: u32 = 1 << 4;
+const CR_OPTER: u32 = 1 << 5;
+const CR_OPTWRE: u32 = 1 << 9;
+
+const OBR_OPTERR: u32 = 1 << 0;
+const OBR_RDPRT: u32 = 1 << 1;
+
+/// Address of the option bytes, each a value byte followed by its complement.
+const OPTION_BYTES: u32 = 0x1FFF_F800;
+
+/// RDP value that disables read-out protection. Any other value enables it.
+const RDP_KEY: u8 = 0xA5;
+
+/// RDP value programmed to enable read-out protection.
+const RDP_PROTECTED: u8 = 0x00;
+
+const ACR_LATENCY_MASK: u32 = 0b111;
+const ACR_HLFCYA: u32 = 1 << 3;
+const ACR_PRFTBE: u32 = 1 << 4;
+const ACR_PRFTBS: u32 = 1 << 5;
+
+/// Number of wait states inserted on flash accesses (LATENCY field of ACR).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Latency
+{
+    /// Zero wait states, for SYSCLK up to 24 MHz
+    Ws0 = 0b000,
+    /// One wait state, for SYSCLK up to 48 MHz
+    Ws1 = 0b001,
+    /// Two wait states, for SYSCLK up to 72 MHz
+    Ws2 = 0b010,
+}
+
+impl Latency
+{
+    /// Returns the wait states required to run from flash at `sysclk` Hz.
+    pub fn for_sysclk(sysclk: u32) -> Self
+    {
+        match sysclk {
+            0..=24_000_000 => Latency::Ws0,
+            24_000_001..=48_000_000 => Latency::Ws1,
+            _ => Latency::Ws2,
+        }
+    }
+}
+
+impl Flash {
+
+    /// Returns the size of the main flash memory in bytes, read from F_SIZE.
+    pub fn size(&self) -> u32 {
+        unsafe { ptr::read_volatile(F_SIZE) as u32 * 1024 }
+    }
+
+    /// Checks whether the flash program/erase controller is locked.
+    pub fn is_locked(&self) -> bool {
+        self.cr.read() & CR_LOCK != 0
+    }
+
+    /// Unlocks the program/erase controller with the KEYR sequence.
+    ///
+    /// The HSI must be running for any program or erase operation.
+    ///
+    /// The writer borrows the `Flash` mutably, so only one can exist at a time and
+    /// none can lock the controller while another one is in use.
+    ///
+    /// # Returns
+    /// A `FlashWriter` that locks the controller again when dropped, or
+    /// `FlashError::Locked` if the controller stayed locked. A wrong key sequence
+    /// keeps it locked until the next reset.
+    ///
+    /// # Example
+    /// ```
+    /// let mut writer = dp.flash.unlock()?;
+    /// let last_page = writer.size() - PAGE_SIZE;
+    /// writer.erase_page(last_page)?;
+    /// writer.program(last_page, &[0x12, 0x34])?;
+    /// ```
+    pub fn unlock(&mut self) -> Result<FlashWriter<'_>, FlashError> {
+        let writer = FlashWriter { flash: self };
+
+        for bank in writer.banks() {
+            if bank.cr.read() & CR_LOCK != 0 {
+                unsafe {
+                    bank.keyr.write(KEY1);
+                    bank.keyr.write(KEY2);
+                }
+            }
+            if bank.cr.read() & CR_LOCK != 0 {
+                return Err(FlashError::Locked);
+            }
+        }
+
+        Ok(writer)
+    }
+
+    /// Returns the number of wait states currently configured.
+    pub fn latency(&self) -> Latency {
+        match self.acr.read() & ACR_LATENCY_MASK {
+            0b000 => Latency::Ws0,
+            0b001 => Latency::Ws1,
+            _ => Latency::Ws2,
+        }
+    }
+
+    /// Sets the number of wait states.
+    ///
+    /// The latency must be raised before SYSCLK is increased and lowered only after
+    /// it has been decreased. Only `Cfgr::freeze()` sets it, so the wait states always
+    /// match the frozen SYSCLK.
+    pub(crate) fn set_latency(&self, latency: Latency) {
+        unsafe { self.acr.modify(|r| (r & !ACR_LATENCY_MASK) | latency as u32) };
+    }
+
+    /// Enables or disables the prefetch buffer.
+    ///
+    /// The buffer can only be switched while SYSCLK is below 24 MHz with no AHB
+    /// prescaler, so this is left to `Cfgr::freeze()`. It is enabled after reset.
+    pub(crate) fn set_prefetch(&self, enable: bool) {
+        if enable {
+            unsafe { self.acr.modify(|r| r | ACR_PRFTBE) };
+        } else {
+            unsafe { self.acr.modify(|r| r & !ACR_PRFTBE) };
+        }
+    }
+
+    /// Checks whether the prefetch buffer is enabled (PRFTBS).
+    pub fn is_prefetch_enabled(&self) -> bool {
+        self.acr.read() & ACR_PRFTBS != 0
+    }
+
+    /// Enables or disables flash half-cycle access.
+    ///
+    /// Half-cycle access saves power but can only be used with a SYSCLK of 8 MHz or
+    /// less that is not taken from the PLL.
+    pub fn set_half_cycle(&self, enable: bool) {
+        if enable {
+            unsafe { self.acr.modify(|r| r | ACR_HLFCYA) };
+        } else {
+            unsafe { self.acr.modify(|r| r & !ACR_HLFCYA) };
+        }
+    }
+
+    /// Checks whether half-cycle access is enabled.
+    pub fn is_half_cycle(&self) -> bool {
+        self.acr.read() & ACR_HLFCYA != 0
+    }
+
+    /// Reads the option bytes from their flash area, checking each complement byte.
+    ///
+    /// These are the programmed values, which only take effect after a system reset.
+    ///
+    /// # Returns
+    /// The option bytes, or `FlashError::InvalidOptionBytes` if a value does not match
+    /// its complement.
+    pub fn option_bytes(&self) -> Result<OptionBytes, FlashError> {
+        let mut bytes = [0; 8];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let half_word = unsafe { ptr::read_volatile((OPTION_BYTES + 2 * i as u32) as *const u16) };
+            let [value, complement] = half_word.to_le_bytes();
+            if value != !complement {
+                return Err(FlashError::InvalidOptionBytes);
+            }
+            *byte = value;
+        }
+        Ok(OptionBytes::from_bytes(bytes))
+    }
+
+    /// Checks whether read-out protection is active (RDPRT of OBR).
+    pub fn is_read_protected(&self) -> bool {
+        self.obr.read() & OBR_RDPRT != 0
+    }
+
+    /// Returns the programmed read-out protection, or the loaded one if the option
+    /// bytes cannot be read.
+    fn read_protection(&self) -> ReadProtection {
+        match self.option_bytes() {
+            Ok(option_bytes) => option_bytes.rdp,
+            Err(_) if self.is_read_protected() => ReadProtection::Protected,
+            Err(_) => ReadProtection::Unprotected,
+        }
+    }
+
+    /// Checks whether an option byte did not match its complement at the last load
+    /// (OPTERR of OBR). Its default value was used instead.
+    pub fn option_byte_error(&self) -> bool {
+        self.obr.read() & OBR_OPTERR != 0
+    }
+
+    /// Returns the loaded USER option byte (OBR[9:2]).
+    pub fn user(&self) -> u8 {
+        (self.obr.read() >> 2) as u8
+    }
+
+    /// Returns the loaded Data0 and Data1 option bytes (OBR[17:10] and OBR[25:18]).
+    pub fn user_data(&self) -> [u8; 2] {
+        let obr = self.obr.read();
+        [(obr >> 10) as u8, (obr >> 18) as u8]
+    }
+
+    /// Returns the loaded write protection (WRPR). A cleared bit means its pages are
+    /// write protected.
+    pub fn write_protection(&self) -> u32 {
+        self.wrpr.read()
+    }
+
+}
+
+/// Error returned by flash program and erase operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlashError
+{
+    /// The controller could not be unlocked.
+    Locked,
+    /// PGERR: the half-word to program was not erased.
+    Programming,
+    /// WRPRTERR: the address is write protected.
+    WriteProtection,
+    /// EOP was not set at the end of the operation.
+    NotCompleted,
+    /// The offset or length is not a multiple of the write or erase size.
+    NotAligned,
+    /// The range goes past the end of the flash memory.
+    OutOfBounds,
+    /// An option byte does not match its complement.
+    InvalidOptionBytes,
+    /// The option bytes read back differ from the programmed ones.
+    VerifyFailed,
+    /// The option bytes would change the read-out protection, which is only done by
+    /// `FlashWriter::set_read_protection`.
+    ReadProtectionChange,
+}
+
+/// Read-out protection level, given by the RDP option byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadProtection
+{
+    /// The flash can be read by the debugger and from RAM or system memory (RDP = 0xA5).
+    Unprotected,
+    /// The flash can only be read by code running from it (any other RDP value).
+    Protected,
+}
+
+impl ReadProtection
+{
+    fn from_byte(rdp: u8) -> Self
+    {
+        if rdp == RDP_KEY {
+            ReadProtection::Unprotected
+        } else {
+            ReadProtection::Protected
+        }
+    }
+
+    fn to_byte(self) -> u8
+    {
+        match self {
+            ReadProtection::Unprotected => RDP_KEY,
+            ReadProtection::Protected => RDP_PROTECTED,
+        }
+    }
+}
+
+/// Option byte values, without their complements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OptionBytes
+{
+    /// Read-out protection, only changed by `FlashWriter::set_read_protection`.
+    pub rdp: ReadProtection,
+    /// USER: WDG_SW (bit 0), nRST_STOP (bit 1), nRST_STDBY (bit 2).
+    pub user: u8,
+    /// Data0 and Data1, free for the application.
+    pub data: [u8; 2],
+    /// WRP0..3: a cleared bit write-protects its group of pages.
+    pub wrp: [u8; 4],
+}
+
+impl OptionBytes
+{
+    fn from_bytes(bytes: [u8; 8]) -> Self
+    {
+        OptionBytes {
+            rdp: ReadProtection::from_byte(bytes[0]),
+            user: bytes[1],
+            data: [bytes[2], bytes[3]],
+            wrp: [bytes[4], bytes[5], bytes[6], bytes[7]],
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 8]
+    {
+        [self.rdp.to_byte(), self.user, self.data[0], self.data[1], self.wrp[0], self.wrp[1], self.wrp[2], self.wrp[3]]
+    }
+}
+
+impl Default for OptionBytes
+{
+    /// Factory values: no read-out or write protection, hardware defaults for USER.
+    fn default() -> Self
+    {
+        OptionBytes { rdp: ReadProtection::Unprotected, user: 0xFF, data: [0xFF; 2], wrp: [0xFF; 4] }
+    }
+}
+
+/// Proof that new option bytes have been programmed but are not loaded yet.
+///
+/// The option bytes are only loaded into OBR and WRPR by a system reset.
+#[must_use = "new option bytes are only applied by a system reset"]
+pub struct ResetRequired
+{
+    _private: (),
+}
+
+impl ResetRequired
+{
+    /// Requests a system reset, which loads the new option bytes.
+    pub fn reset(self) -> !
+    {
+        cortex_m::peripheral::SCB::sys_reset()
+    }
+}
+
+impl NorFlashError for FlashError
+{
+    fn kind(&self) -> NorFlashErrorKind
+    {
+        match self {
+            FlashError::NotAligned => NorFlashErrorKind::NotAligned,
+            FlashError::OutOfBounds => NorFlashErrorKind::OutOfBounds,
+            _ => NorFlashErrorKind::Other,
+        }
+    }
+}
+
+/// Control registers of one flash bank.
+struct Bank<'a>
+{
+    keyr: &'a RW<u32>,
+    sr:   &'a RW<u32>,
+    cr:   &'a RW<u32>,
+    ar:   &'a RW<u32>,
+}
+
+impl Bank<'_>
+{
+    /// Waits for the bank to be idle and clears the flags left by an earlier operation.
+    fn prepare(&self)
+    {
+        while self.sr.read() & SR_BSY != 0 {}
+        unsafe { self.sr.write(SR_EOP | SR_WRPRTERR | SR_PGERR) };
+    }
+
+    /// Waits for the running operation, then clears and checks the status flags.
+    fn wait(&self) -> Result<(), FlashError>
+    {
+        while self.sr.read() & SR_BSY != 0 {}
+
+        let sr = self.sr.read();
+        unsafe { self.sr.write(SR_EOP | SR_WRPRTERR | SR_PGERR) };
+
+        if sr & SR_PGERR != 0 {
+            Err(FlashError::Programming)
+        } else if sr & SR_WRPRTERR != 0 {
+            Err(FlashError::WriteProtection)
+        } else if sr & SR_EOP == 0 {
+            Err(FlashError::NotCompleted)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Unlocked flash program/erase controller, returned by `Flash::unlock()`.
+///
+/// Offsets are relative to `FLASH_START`. The controller is locked again when the
+/// writer is dropped. While an operation runs, code fetches from flash stall.
+pub struct FlashWriter<'a>
+{
+    flash: &'a Flash,
+}
+
+impl<'a> FlashWriter<'a>
+{
+    fn banks(&self) -> impl Iterator<Item = Bank<'a>>
+    {
+        let flash = self.flash;
+        let bank1 = Bank { keyr: &flash.keyr, sr: &flash.sr, cr: &flash.cr, ar: &flash.ar };
+        #[cfg(feature = "xl-density")]
+        let banks = [bank1, Bank { keyr: &flash.keyr2, sr: &flash.sr2, cr: &flash.cr2, ar: &flash.ar2 }];
+        #[cfg(not(feature = "xl-density"))]
+        let banks = [bank1];
+        banks.into_iter()
+    }
+
+    /// Returns the bank holding `offset`.
+    fn bank(&self, offset: u32) -> Bank<'a>
+    {
+        let flash = self.flash;
+        #[cfg(feature = "xl-density")]
+        if offset >= BANK2_OFFSET {
+            return Bank { keyr: &flash.keyr2, sr: &flash.sr2, cr: &flash.cr2, ar: &flash.ar2 };
+        }
+        let _ = offset;
+        Bank { keyr: &flash.keyr, sr: &flash.sr, cr: &flash.cr, ar: &flash.ar }
+    }
+
+    /// Returns the size of the main flash memory in bytes, see `Flash::size()`.
+    pub fn size(&self) -> u32
+    {
+        self.flash.size()
+    }
+
+    /// Checks that `offset..offset + len` lies in the flash memory.
+    fn check_bounds(&self, offset: u32, len: usize) -> Result<(), FlashError>
+    {
+        match u32::try_from(len).ok().and_then(|len| offset.checked_add(len)) {
+            Some(end) if end <= self.flash.size() => Ok(()),
+            _ => Err(FlashError::OutOfBounds),
+        }
+    }
+
+    /// Erases the page starting at `offset`.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset of the page, a multiple of `PAGE_SIZE`.
+    pub fn erase_page(&mut self, offset: u32) -> Result<(), FlashError>
+    {
+        if !offset.is_multiple_of(PAGE_SIZE) {
+            return Err(FlashError::NotAligned);
+        }
+        self.check_bounds(offset, PAGE_SIZE as usize)?;
+
+        let bank = self.bank(offset);
+        bank.prepare();
+        unsafe {
+            bank.cr.modify(|r| r | CR_PER);
+            bank.ar.write(FLASH_START + offset);
+            bank.cr.modify(|r| r | CR_STRT);
+        }
+        let result = bank.wait();
+        unsafe { bank.cr.modify(|r| r & !CR_PER) };
+
+        result
+    }
+
+    /// Erases the whole flash memory.
+    ///
+    /// # Safety
+    /// This also erases the running program: it must be called from code executing in
+    /// RAM, and nothing may be fetched from flash afterwards until it is reprogrammed.
+    pub unsafe fn erase_all(&mut self) -> Result<(), FlashError>
+    {
+        for bank in self.banks() {
+            bank.prepare();
+            bank.cr.modify(|r| r | CR_MER);
+            bank.cr.modify(|r| r | CR_STRT);
+            let result = bank.wait();
+            bank.cr.modify(|r| r & !CR_MER);
+            result?;
+        }
+
+        Ok(())
+    }
+
+    /// Programs `data` at `offset`, one half-word at a time (little endian).
+    ///
+    /// The target half-words must be erased, except when programming 0x0000.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset of the first byte, a multiple of 2.
+    /// * `data` - Bytes to program, an even number of them.
+    pub fn program(&mut self, offset: u32, data: &[u8]) -> Result<(), FlashError>
+    {
+        if !offset.is_multiple_of(2) || !data.len().is_multiple_of(2) {
+            return Err(FlashError::NotAligned);
+        }
+        self.check_bounds(offset, data.len())?;
+
+        for (i, chunk) in data.chunks_exact(2).enumerate() {
+            let address = offset + 2 * i as u32;
+            let bank = self.bank(address);
+            let half_word = u16::from_le_bytes([chunk[0], chunk[1]]);
+
+            bank.prepare();
+            unsafe {
+                bank.cr.modify(|r| r | CR_PG);
+                ptr::write_volatile((FLASH_START + address) as *mut u16, half_word);
+            }
+            let result = bank.wait();
+            unsafe { bank.cr.modify(|r| r & !CR_PG) };
+            result?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `bytes.len()` bytes starting at `offset`.
+    pub fn read(&self, offset: u32, bytes: &mut [u8]) -> Result<(), FlashError>
+    {
+        self.check_bounds(offset, bytes.len())?;
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = unsafe { ptr::read_volatile((FLASH_START + offset + i as u32) as *const u8) };
+        }
+
+        Ok(())
+    }
+
+    /// Reads the programmed option bytes, see `Flash::option_bytes`.
+    pub fn option_bytes(&self) -> Result<OptionBytes, FlashError>
+    {
+        self.flash.option_bytes()
+    }
+
+    /// Erases and programs all the option bytes, then reads them back.
+    ///
+    /// The option byte area is unlocked with the OPTKEYR sequence and locked again
+    /// afterwards; the complement bytes are written by the hardware. The read-out
+    /// protection must stay as programmed, it is changed with `set_read_protection`.
+    ///
+    /// # Returns
+    /// A `ResetRequired` to apply the new values, `FlashError::ReadProtectionChange` if
+    /// `rdp` differs from the programmed value, or a `FlashError` if the option bytes
+    /// could not be unlocked, programmed or verified.
+    ///
+    /// # Example
+    /// ```
+    /// let mut option_bytes = writer.option_bytes()?;
+    /// option_bytes.data[0] = BOARD_REVISION;
+    /// writer.program_option_bytes(&option_bytes)?.reset();
+    /// ```
+    pub fn program_option_bytes(&mut self, option_bytes: &OptionBytes) -> Result<ResetRequired, FlashError>
+    {
+        if option_bytes.rdp != self.flash.read_protection() {
+            return Err(FlashError::ReadProtectionChange);
+        }
+
+        self.reprogram_option_bytes(option_bytes)
+    }
+
+    /// Changes the read-out protection, keeping the other option bytes as programmed.
+    ///
+    /// # Returns
+    /// A `ResetRequired` to apply the new protection, or a `FlashError` if the option
+    /// bytes could not be read, unlocked, programmed or verified.
+    ///
+    /// # Safety
+    /// Going from `Protected` to `Unprotected` mass erases the main flash, including the
+    /// running program: this must then be called from code executing in RAM. Going to
+    /// `Protected` blocks debugger access to the flash until it is removed that way.
+    pub unsafe fn set_read_protection(&mut self, protection: ReadProtection) -> Result<ResetRequired, FlashError>
+    {
+        let option_bytes = OptionBytes { rdp: protection, ..self.flash.option_bytes()? };
+        self.reprogram_option_bytes(&option_bytes)
+    }
+
+    fn reprogram_option_bytes(&mut self, option_bytes: &OptionBytes) -> Result<ResetRequired, FlashError>
+    {
+        let flash = self.flash;
+        let bank = self.bank(0);
+
+        unsafe {
+            flash.optkeyr.write(KEY1);
+            flash.optkeyr.write(KEY2);
+        }
+        if bank.cr.read() & CR_OPTWRE == 0 {
+            return Err(FlashError::Locked);
+        }
+
+        let result = Self::write_option_bytes(&bank, option_bytes);
+        unsafe { bank.cr.modify(|r| r & !(CR_OPTER | CR_OPTPG | CR_OPTWRE)) };
+        result?;
+
+        if flash.option_bytes()? != *option_bytes {
+            return Err(FlashError::VerifyFailed);
+        }
+
+        Ok(ResetRequired { _private: () })
+    }
+
+    fn write_option_bytes(bank: &Bank<'_>, option_bytes: &OptionBytes) -> Result<(), FlashError>
+    {
+        bank.prepare();
+        unsafe {
+            bank.cr.modify(|r| r | CR_OPTER);
+            bank.cr.modify(|r| r | CR_OPTER | CR_STRT);
+        }
+        bank.wait()?;
+        unsafe { bank.cr.modify(|r| (r & !CR_OPTER) | CR_OPTPG) };
+
+        for (i, byte) in option_bytes.to_bytes().iter().enumerate() {
+            unsafe { ptr::write_volatile((OPTION_BYTES + 2 * i as u32) as *mut u16, *byte as u16) };
+            bank.wait()?;
+        }
+
+        Ok(())
+    }
+
+    /// Drops the writer, which locks the controller again. Dropping it any other way
+    /// has the same effect.
+    pub fn lock(self) {}
+}
+
+impl Drop for FlashWriter<'_>
+{
+    fn drop(&mut self)
+    {
+        for bank in self.banks() {
+            unsafe { bank.cr.modify(|r| r | CR_LOCK) };
+        }
+    }
+}
+
+impl ErrorType for FlashWriter<'_>
+{
+    type Error = FlashError;
+}
+
+impl ReadNorFlash for FlashWriter<'_>
+{
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>
+    {
+        FlashWriter::read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize
+    {
+        self.flash.size() as usize
+    }
+}
+
+impl NorFlash for FlashWriter<'_>
+{
+    const WRITE_SIZE: usize = 2;
+    const ERASE_SIZE: usize = PAGE_SIZE as usize;
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error>
+    {
+        if from > to {
+            return Err(FlashError::OutOfBounds);
+        }
+        if !from.is_multiple_of(PAGE_SIZE) || !to.is_multiple_of(PAGE_SIZE) {
+            return Err(FlashError::NotAligned);
+        }
+        self.check_bounds(from, (to - from) as usize)?;
+
+        for page in (from..to).step_by(PAGE_SIZE as usize) {
+            self.erase_page(page)?;
+        }
+
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error>
+    {
+        self.program(offset, bytes)
+    }
+}
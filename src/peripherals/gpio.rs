@@ -1,8 +1,16 @@
 
-use pin::{Disabled, PinConfig};
+use core::{marker::PhantomData, ops::Deref};
+
+use pin::PinConfig;
 use volatile_register::*;
 
 pub mod pin;
+pub mod erased;
+pub mod bus;
+pub mod dynamic;
+pub mod batch;
+pub mod debounce;
+pub mod lock;
 
 pub struct Gpio
 {
@@ -15,32 +23,312 @@ pub struct Gpio
     pub lckr: RW<u32>,
 }
 
-macro_rules! pin {
-    ($px: ident, $num: literal) => (
-        pub fn $px(&self) -> PinConfig<$num, Disabled> 
+impl Gpio
+{
+    /// Returns the register block of GPIO port `port` (`'A'`..`'G'`).
+    #[inline(always)]
+    pub(crate) fn port(port: char) -> &'static Gpio
+    {
+        let address = match port {
+            'A' => 0x4001_0800,
+            'B' => 0x4001_0C00,
+            'C' => 0x4001_1000,
+            'D' => 0x4001_1400,
+            'E' => 0x4001_1800,
+            'F' => 0x4001_1C00,
+            'G' => 0x4001_2000,
+            _ => unreachable!(),
+        };
+
+        unsafe {
+            & *(address as *const Gpio)
+        }
+    }
+
+    /// Writes the CNF/MODE bits of pin `pin` into CRL (pins 0-7) or CRH (pins 8-15).
+    /// 
+    /// The whole 4-bit field is replaced in a single write, so the pin never passes
+    /// through an intermediate configuration.
+    pub(crate) fn set_mode(&self, pin: u8, cnf: u32, mode: u32)
+    {
+        let bits = (cnf << 2) | mode;
+
+        match pin 
         {
-            PinConfig::<$num, Disabled>::new(self)
+            0..=7 => {
+                unsafe {    
+                    self.crl.modify(|r|{
+                        let offset = pin * 4;
+                        r & !(0b1111 << offset) | (bits << offset)
+                    })
+                }
+            }
+            8..=15 => {
+                unsafe {    
+                    self.crh.modify(|r|{
+                        let offset = (pin - 8)* 4;
+                        r & !(0b1111 << offset) | (bits << offset)
+                    })
+                }
+
+            }
+            _ => ()
         }
-    )
+    }
+
+    /// Locks the configuration (CRL/CRH) of the pins selected by `mask` until the next reset.
+    /// 
+    /// The LCKR lock sequence is run once for all the pins:
+    /// write LCKK=1, write LCKK=0, write LCKK=1, read, read. The last read must return
+    /// LCKK set for the lock to be active. A port can only be locked once per reset.
+    /// Drivers go through `Port::lock()`, which takes the pins so their type follows.
+    pub(crate) fn lock_mask(&self, mask: u16) -> Result<(), LockError>
+    {
+        if mask == 0 {
+            return Err(LockError::NoPins);
+        }
+        if self.is_port_locked() {
+            return Err(LockError::AlreadyLocked);
+        }
+
+        let pins = mask as u32;
+
+        // LCKR[15:0] must keep the same value during the whole sequence
+        unsafe {
+            self.lckr.write(LCKK | pins);
+            self.lckr.write(pins);
+            self.lckr.write(LCKK | pins);
+        }
+        self.lckr.read();
+
+        if self.is_port_locked() {
+            Ok(())
+        } else {
+            Err(LockError::NotLocked)
+        }
+    }
+
+    /// Checks whether the lock sequence has been applied to the port (LCKK set).
+    pub fn is_port_locked(&self) -> bool
+    {
+        (self.lckr.read() & LCKK) != 0
+    }
+
+    /// Checks whether the configuration of pin `pin` is locked. Returns `false` for a
+    /// pin number above 15.
+    pub fn is_locked(&self, pin: u8) -> bool
+    {
+        let lckr = self.lckr.read();
+        pin < 16 && (lckr & LCKK) != 0 && (lckr & (1 << pin)) != 0
+    }
+
+    /// Drives the pins selected by `mask` to the matching bits of `value`.
+    /// 
+    /// Both the set and the reset half of BSRR are written at once, so all the
+    /// selected pins change in the same cycle. Pins outside `mask` are untouched.
+    ///
+    /// # Example
+    /// ```
+    /// // PA0..PA7 = 0xA5, PA8..PA15 unchanged
+    /// gpioa.port.write_masked(0x00FF, 0x00A5);
+    /// ```
+    pub fn write_masked(&self, mask: u16, value: u16)
+    {
+        let set = (mask & value) as u32;
+        let reset = (mask & !value) as u32;
+
+        unsafe {
+            self.bsrr.write((reset << 16) | set);
+        }
+    }
+
+    /// Inverts the level driven by the pins selected by `mask`.
+    /// 
+    /// ODR is read once and the opposite levels are written with a single BSRR
+    /// write, so all the selected pins toggle in the same cycle.
+    ///
+    /// # Example
+    /// ```
+    /// // toggle PA0 and PA5 together
+    /// gpioa.port.toggle_masked((1 << 0) | (1 << 5));
+    /// ```
+    pub fn toggle_masked(&self, mask: u16)
+    {
+        let odr = self.odr.read();
+        let set = !odr & mask as u32;
+        let reset = odr & mask as u32;
+
+        unsafe {
+            self.bsrr.write((reset << 16) | set);
+        }
+    }
+}
+
+/// LCKK bit of the LCKR register.
+const LCKK: u32 = 1 << 16;
+
+/// Error returned when the LCKR lock sequence does not take effect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockError
+{
+    /// No pin was given, which would spend the port's only lock for nothing.
+    NoPins,
+    /// The port was already locked; its lock can only be released by a reset.
+    AlreadyLocked,
+    /// LCKK did not read back as set at the end of the sequence.
+    NotLocked,
 }
 
-impl Gpio {
-   pin!(p0, 0);
-   pin!(p1, 1);
-   pin!(p2, 2);
-   pin!(p3, 3);
-   pin!(p4, 4);
-   pin!(p5, 5);
-   pin!(p6, 6);
-   pin!(p7, 7);
-   pin!(p8, 8);
-   pin!(p9, 9);
-   pin!(p10, 10);
-   pin!(p11, 11);
-   pin!(p12, 12);
-   pin!(p13, 13);
-   pin!(p14, 14);
-   pin!(p15, 15);
+/// Pins of a port whose configuration has been locked until the next reset,
+/// returned by `Port::lock()`.
+pub struct LockedPins<PINS>
+{
+    mask: u16,
+    pins: PINS,
+}
+
+impl<PINS> LockedPins<PINS>
+{
+    /// Returns the mask of the locked pins.
+    pub fn mask(&self) -> u16
+    {
+        self.mask
+    }
+
+    /// Checks whether pin `pin` is part of the locked set. Returns `false` for a pin
+    /// number above 15.
+    pub fn is_locked(&self, pin: u8) -> bool
+    {
+        pin < 16 && (self.mask & (1 << pin)) != 0
+    }
+
+    /// Returns the pins, in the `Locked` state. Outputs can still be driven and inputs
+    /// read.
+    pub fn release(self) -> PINS
+    {
+        self.pins
+    }
+}
+
+/// Port-level handle returned by `split()`, for operations spanning several pins of port `P`.
+pub struct Port<const P: char>
+{
+    _marker: PhantomData<Gpio>,
+}
+
+impl<const P: char> Deref for Port<P>
+{
+    type Target = Gpio;
+    fn deref(&self) -> &Self::Target {
+        Gpio::port(P)
+    }
+}
+
+macro_rules! gpio {
+    ($GpioX: ident, $gpiox: ident, $port: literal, $enable: ident, $reset: ident, [$($PXi: ident: ($px: ident, $num: literal, $STATE: ident)),+ $(,)?]) => (
+        $(
+            pub type $PXi<STATE> = PinConfig<$port, $num, STATE>;
+        )+
+
+        pub mod $gpiox {
+            use super::{pin, Port, $($PXi),+};
+
+            /// The pins of the port, each one owned and zero-sized.
+            ///
+            /// The SWJ debug pins (PA13, PA14, PA15, PB3, PB4) start in the `Debugger`
+            /// state and are released through `afio::Parts`.
+            pub struct Parts
+            {
+                pub port: Port<$port>,
+                $(
+                    pub $px: $PXi<pin::$STATE>,
+                )+
+            }
+        }
+
+        impl<'a> super::$GpioX<'a> {
+            /// Splits the port into its individual pins.
+            /// 
+            /// This consumes the port, enables its clock and resets it, so every pin can only be
+            /// owned once and starts in its reset configuration.
+            /// The port wrapper gives no access to the registers on its own, so pins and the
+            /// `Port` handle can only be obtained once the clock is running.
+            ///
+            /// # Arguments
+            /// * `rcc` - Reference to the `Rcc` used to enable and reset the port.
+            ///
+            /// # Example
+            /// ```
+            /// let gpioa = dp.gpioa.split(rcc.bus);
+            /// let pa1 = gpioa.p1.into_output_pushpull(PinSpeed::Speed2Hz);
+            /// ```
+            pub fn split(self, rcc: &super::rcc::Rcc) -> $gpiox::Parts
+            {
+                rcc.$enable();
+                rcc.$reset();
+
+                $gpiox::Parts {
+                    port: Port { _marker: PhantomData },
+                    $(
+                        $px: PinConfig::new(),
+                    )+
+                }
+            }
+        }
+    )
 }
 
+gpio!(GpioA, gpioa, 'A', enable_gpioa, reset_gpioa, [
+    PA0: (p0, 0, Disabled), PA1: (p1, 1, Disabled), PA2: (p2, 2, Disabled), PA3: (p3, 3, Disabled),
+    PA4: (p4, 4, Disabled), PA5: (p5, 5, Disabled), PA6: (p6, 6, Disabled), PA7: (p7, 7, Disabled),
+    PA8: (p8, 8, Disabled), PA9: (p9, 9, Disabled), PA10: (p10, 10, Disabled), PA11: (p11, 11, Disabled),
+    PA12: (p12, 12, Disabled), PA13: (p13, 13, Debugger), PA14: (p14, 14, Debugger), PA15: (p15, 15, Debugger),
+]);
+
+gpio!(GpioB, gpiob, 'B', enable_gpiob, reset_gpiob, [
+    PB0: (p0, 0, Disabled), PB1: (p1, 1, Disabled), PB2: (p2, 2, Disabled), PB3: (p3, 3, Debugger),
+    PB4: (p4, 4, Debugger), PB5: (p5, 5, Disabled), PB6: (p6, 6, Disabled), PB7: (p7, 7, Disabled),
+    PB8: (p8, 8, Disabled), PB9: (p9, 9, Disabled), PB10: (p10, 10, Disabled), PB11: (p11, 11, Disabled),
+    PB12: (p12, 12, Disabled), PB13: (p13, 13, Disabled), PB14: (p14, 14, Disabled), PB15: (p15, 15, Disabled),
+]);
+
+#[cfg(not(feature = "vfqfpn36"))]
+gpio!(GpioC, gpioc, 'C', enable_gpioc, reset_gpioc, [
+    PC0: (p0, 0, Disabled), PC1: (p1, 1, Disabled), PC2: (p2, 2, Disabled), PC3: (p3, 3, Disabled),
+    PC4: (p4, 4, Disabled), PC5: (p5, 5, Disabled), PC6: (p6, 6, Disabled), PC7: (p7, 7, Disabled),
+    PC8: (p8, 8, Disabled), PC9: (p9, 9, Disabled), PC10: (p10, 10, Disabled), PC11: (p11, 11, Disabled),
+    PC12: (p12, 12, Disabled), PC13: (p13, 13, Disabled), PC14: (p14, 14, Disabled), PC15: (p15, 15, Disabled),
+]);
+
+gpio!(GpioD, gpiod, 'D', enable_gpiod, reset_gpiod, [
+    PD0: (p0, 0, Disabled), PD1: (p1, 1, Disabled), PD2: (p2, 2, Disabled), PD3: (p3, 3, Disabled),
+    PD4: (p4, 4, Disabled), PD5: (p5, 5, Disabled), PD6: (p6, 6, Disabled), PD7: (p7, 7, Disabled),
+    PD8: (p8, 8, Disabled), PD9: (p9, 9, Disabled), PD10: (p10, 10, Disabled), PD11: (p11, 11, Disabled),
+    PD12: (p12, 12, Disabled), PD13: (p13, 13, Disabled), PD14: (p14, 14, Disabled), PD15: (p15, 15, Disabled),
+]);
+
+#[cfg(not(any(feature = "vfqfpn36", feature = "lqfp48", feature = "lqfp64")))]
+gpio!(GpioE, gpioe, 'E', enable_gpioe, reset_gpioe, [
+    PE0: (p0, 0, Disabled), PE1: (p1, 1, Disabled), PE2: (p2, 2, Disabled), PE3: (p3, 3, Disabled),
+    PE4: (p4, 4, Disabled), PE5: (p5, 5, Disabled), PE6: (p6, 6, Disabled), PE7: (p7, 7, Disabled),
+    PE8: (p8, 8, Disabled), PE9: (p9, 9, Disabled), PE10: (p10, 10, Disabled), PE11: (p11, 11, Disabled),
+    PE12: (p12, 12, Disabled), PE13: (p13, 13, Disabled), PE14: (p14, 14, Disabled), PE15: (p15, 15, Disabled),
+]);
+
+#[cfg(feature = "lqfp144")]
+gpio!(GpioF, gpiof, 'F', enable_gpiof, reset_gpiof, [
+    PF0: (p0, 0, Disabled), PF1: (p1, 1, Disabled), PF2: (p2, 2, Disabled), PF3: (p3, 3, Disabled),
+    PF4: (p4, 4, Disabled), PF5: (p5, 5, Disabled), PF6: (p6, 6, Disabled), PF7: (p7, 7, Disabled),
+    PF8: (p8, 8, Disabled), PF9: (p9, 9, Disabled), PF10: (p10, 10, Disabled), PF11: (p11, 11, Disabled),
+    PF12: (p12, 12, Disabled), PF13: (p13, 13, Disabled), PF14: (p14, 14, Disabled), PF15: (p15, 15, Disabled),
+]);
+
+#[cfg(feature = "lqfp144")]
+gpio!(GpioG, gpiog, 'G', enable_gpiog, reset_gpiog, [
+    PG0: (p0, 0, Disabled), PG1: (p1, 1, Disabled), PG2: (p2, 2, Disabled), PG3: (p3, 3, Disabled),
+    PG4: (p4, 4, Disabled), PG5: (p5, 5, Disabled), PG6: (p6, 6, Disabled), PG7: (p7, 7, Disabled),
+    PG8: (p8, 8, Disabled), PG9: (p9, 9, Disabled), PG10: (p10, 10, Disabled), PG11: (p11, 11, Disabled),
+    PG12: (p12, 12, Disabled), PG13: (p13, 13, Disabled), PG14: (p14, 14, Disabled), PG15: (p15, 15, Disabled),
+]);
+
 pub use pin::PinSpeed; 
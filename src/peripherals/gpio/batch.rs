@@ -0,0 +1,208 @@
+use super::{Gpio, Port};
+use super::pin::{
+    AlternateOpenDrain, AlternatePushPull, Analog, Floating, InputPullDown, InputPullUp, OpenDrain, PinConfig, PinSpeed,
+    PinState, PushPull,
+};
+
+/// A tuple that can be extended by one element, used to collect the pins of a `Batch`.
+pub trait Append
+{
+    type Output<T>;
+
+    fn append<T>(self, value: T) -> Self::Output<T>;
+}
+
+macro_rules! append {
+    ($($T: ident $i: tt),*) => {
+        impl<$($T),*> Append for ($($T,)*)
+        {
+            type Output<T> = ($($T,)* T,);
+
+            fn append<T>(self, value: T) -> Self::Output<T>
+            {
+                ($(self.$i,)* value,)
+            }
+        }
+    }
+}
+
+append!();
+append!(T0 0);
+append!(T0 0, T1 1);
+append!(T0 0, T1 1, T2 2);
+append!(T0 0, T1 1, T2 2, T3 3);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11, T12 12);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11, T12 12, T13 13);
+append!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11, T12 12, T13 13, T14 14);
+
+/// Configuration of several pins of port `P`, committed at once.
+///
+/// Each call records the mode of one pin; `commit()` then writes CRL and CRH once
+/// each, sets the pull directions with a single BSRR write, and returns the
+/// reconfigured pins as a tuple, in the order they were added.
+///
+/// # Example
+/// ```
+/// let (led, button, tx) = gpioa.port.batch()
+///     .output_pushpull(gpioa.p1, PinSpeed::Speed2Hz)
+///     .input_pullup(gpioa.p0)
+///     .alternate_pushpull(gpioa.p9, PinSpeed::Speed50Hz)
+///     .commit();
+/// ```
+pub struct Batch<const P: char, PINS>
+{
+    crl_mask: u32,
+    crl_bits: u32,
+    crh_mask: u32,
+    crh_bits: u32,
+    odr_set: u32,
+    odr_reset: u32,
+    pins: PINS,
+}
+
+impl<const P: char> Port<P>
+{
+    /// Starts a batch configuration of pins of this port.
+    pub fn batch(&self) -> Batch<P, ()>
+    {
+        Batch {
+            crl_mask: 0,
+            crl_bits: 0,
+            crh_mask: 0,
+            crh_bits: 0,
+            odr_set: 0,
+            odr_reset: 0,
+            pins: (),
+        }
+    }
+}
+
+impl<const P: char, PINS: Append> Batch<P, PINS>
+{
+    /// Records the CNF/MODE bits of pin `N`, and its ODR level when it selects a pull direction.
+    fn add<const N: u8, STATE: PinState, NEW>(mut self, _pin: PinConfig<P, N, STATE>, cnf: u32, mode: u32, pull: Option<bool>)
+        -> Batch<P, PINS::Output<PinConfig<P, N, NEW>>>
+    {
+        let bits = (cnf << 2) | mode;
+
+        if N < 8 {
+            let offset = N * 4;
+            self.crl_mask |= 0b1111 << offset;
+            self.crl_bits = self.crl_bits & !(0b1111 << offset) | (bits << offset);
+        } else {
+            let offset = (N - 8) * 4;
+            self.crh_mask |= 0b1111 << offset;
+            self.crh_bits = self.crh_bits & !(0b1111 << offset) | (bits << offset);
+        }
+
+        self.odr_set &= !(1 << N);
+        self.odr_reset &= !(1 << N);
+        match pull {
+            Some(true) => self.odr_set |= 1 << N,
+            Some(false) => self.odr_reset |= 1 << N,
+            None => (),
+        }
+
+        Batch {
+            crl_mask: self.crl_mask,
+            crl_bits: self.crl_bits,
+            crh_mask: self.crh_mask,
+            crh_bits: self.crh_bits,
+            odr_set: self.odr_set,
+            odr_reset: self.odr_reset,
+            pins: self.pins.append(PinConfig::new()),
+        }
+    }
+
+    /// Adds a pin to configure as a push-pull output.
+    pub fn output_pushpull<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>, pin_speed: PinSpeed)
+        -> Batch<P, PINS::Output<PinConfig<P, N, PushPull>>>
+    {
+        self.add(pin, 0b00, pin_speed as u32, None)
+    }
+
+    /// Adds a pin to configure as an open-drain output.
+    pub fn output_opendrain<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>, pin_speed: PinSpeed)
+        -> Batch<P, PINS::Output<PinConfig<P, N, OpenDrain>>>
+    {
+        self.add(pin, 0b01, pin_speed as u32, None)
+    }
+
+    /// Adds a pin to configure as an alternate function push-pull output.
+    pub fn alternate_pushpull<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>, pin_speed: PinSpeed)
+        -> Batch<P, PINS::Output<PinConfig<P, N, AlternatePushPull>>>
+    {
+        self.add(pin, 0b10, pin_speed as u32, None)
+    }
+
+    /// Adds a pin to configure as an alternate function open-drain output.
+    pub fn alternate_opendrain<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>, pin_speed: PinSpeed)
+        -> Batch<P, PINS::Output<PinConfig<P, N, AlternateOpenDrain>>>
+    {
+        self.add(pin, 0b11, pin_speed as u32, None)
+    }
+
+    /// Adds a pin to configure as an analog input.
+    pub fn analog<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>)
+        -> Batch<P, PINS::Output<PinConfig<P, N, Analog>>>
+    {
+        self.add(pin, 0b00, 0b00, None)
+    }
+
+    /// Adds a pin to configure as a floating input.
+    pub fn input_floating<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>)
+        -> Batch<P, PINS::Output<PinConfig<P, N, Floating>>>
+    {
+        self.add(pin, 0b01, 0b00, None)
+    }
+
+    /// Adds a pin to configure as an input with an internal pull-up resistor.
+    pub fn input_pullup<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>)
+        -> Batch<P, PINS::Output<PinConfig<P, N, InputPullUp>>>
+    {
+        self.add(pin, 0b10, 0b00, Some(true))
+    }
+
+    /// Adds a pin to configure as an input with an internal pull-down resistor.
+    pub fn input_pulldown<const N: u8, STATE: PinState>(self, pin: PinConfig<P, N, STATE>)
+        -> Batch<P, PINS::Output<PinConfig<P, N, InputPullDown>>>
+    {
+        self.add(pin, 0b10, 0b00, Some(false))
+    }
+}
+
+impl<const P: char, PINS> Batch<P, PINS>
+{
+    /// Writes the recorded configuration and returns the reconfigured pins.
+    /// 
+    /// The pull directions are applied first with a single BSRR write, then CRL and CRH
+    /// are each updated with a single write (only if one of their pins was added). This
+    /// is the order of the single-pin transitions on `PinConfig`, which documents the
+    /// resulting glitch.
+    pub fn commit(self) -> PINS
+    {
+        let gpio = Gpio::port(P);
+
+        unsafe {
+            if (self.odr_set | self.odr_reset) != 0 {
+                gpio.bsrr.write((self.odr_reset << 16) | self.odr_set);
+            }
+            if self.crl_mask != 0 {
+                gpio.crl.modify(|r| r & !self.crl_mask | self.crl_bits);
+            }
+            if self.crh_mask != 0 {
+                gpio.crh.modify(|r| r & !self.crh_mask | self.crh_bits);
+            }
+        }
+
+        self.pins
+    }
+}
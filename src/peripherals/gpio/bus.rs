@@ -0,0 +1,117 @@
+use super::Gpio;
+use super::erased::PartiallyErasedPin;
+use super::pin::{OutputState, PinConfig};
+
+/// A group of output pins of one port that can be written as a single word.
+///
+/// Implemented for tuples of up to 16 `PinConfig` output pins and for arrays of
+/// `PartiallyErasedPin` output pins, all on the same port. The first pin of the
+/// group is bit 0 of the word.
+pub trait BusPins
+{
+    /// Port the pins belong to.
+    const PORT: char;
+
+    /// Number of pins in the group, at most 16.
+    const WIDTH: usize;
+
+    /// Returns the BSRR value that drives every pin of the group to its bit of `word`.
+    fn bsrr_bits(&self, word: u16) -> u32;
+}
+
+#[inline(always)]
+fn bit(pin: u8, high: bool) -> u32
+{
+    if high { 1 << pin } else { 1 << (pin + 16) }
+}
+
+macro_rules! bus_pins {
+    ($width: literal; $($S: ident: $N: ident: $i: tt),+) => {
+        impl<const P: char, $(const $N: u8, $S: OutputState),+> BusPins for ($(PinConfig<P, $N, $S>,)+)
+        {
+            const PORT: char = P;
+            const WIDTH: usize = $width;
+
+            fn bsrr_bits(&self, word: u16) -> u32
+            {
+                0 $(| bit($N, word & (1 << $i) != 0))+
+            }
+        }
+    }
+}
+
+bus_pins!(1; S0: N0: 0);
+bus_pins!(2; S0: N0: 0, S1: N1: 1);
+bus_pins!(3; S0: N0: 0, S1: N1: 1, S2: N2: 2);
+bus_pins!(4; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3);
+bus_pins!(5; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4);
+bus_pins!(6; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5);
+bus_pins!(7; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6);
+bus_pins!(8; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7);
+bus_pins!(9; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8);
+bus_pins!(10; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9);
+bus_pins!(11; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10);
+bus_pins!(12; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10, S11: N11: 11);
+bus_pins!(13; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10, S11: N11: 11, S12: N12: 12);
+bus_pins!(14; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10, S11: N11: 11, S12: N12: 12, S13: N13: 13);
+bus_pins!(15; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10, S11: N11: 11, S12: N12: 12, S13: N13: 13, S14: N14: 14);
+bus_pins!(16; S0: N0: 0, S1: N1: 1, S2: N2: 2, S3: N3: 3, S4: N4: 4, S5: N5: 5, S6: N6: 6, S7: N7: 7, S8: N8: 8, S9: N9: 9, S10: N10: 10, S11: N11: 11, S12: N12: 12, S13: N13: 13, S14: N14: 14, S15: N15: 15);
+
+impl<const P: char, STATE: OutputState, const W: usize> BusPins for [PartiallyErasedPin<P, STATE>; W]
+{
+    const PORT: char = P;
+    const WIDTH: usize = W;
+
+    fn bsrr_bits(&self, word: u16) -> u32
+    {
+        self.iter()
+            .enumerate()
+            .fold(0, |bits, (i, pin)| bits | bit(pin.pin_number(), word & (1 << i) != 0))
+    }
+}
+
+/// A parallel bus made of output pins of one port, written atomically.
+///
+/// Every write updates all the pins of the bus with a single BSRR write, so there is
+/// no skew between the lines (8-bit LCD data bus, 7-segment display, ...).
+///
+/// # Example
+/// ```
+/// let mut bus = ParallelBus::new((
+///     gpioa.p0.into_output_pushpull(PinSpeed::Speed50Hz),
+///     gpioa.p1.into_output_pushpull(PinSpeed::Speed50Hz),
+///     gpioa.p4.into_output_pushpull(PinSpeed::Speed50Hz),
+///     gpioa.p5.into_output_pushpull(PinSpeed::Speed50Hz),
+/// ));
+/// bus.write(0b1010);
+/// ```
+pub struct ParallelBus<PINS>
+{
+    pins: PINS,
+}
+
+impl<PINS: BusPins> ParallelBus<PINS>
+{
+    /// Groups `pins` into a bus.
+    ///
+    /// A group of more than 16 pins does not compile.
+    pub fn new(pins: PINS) -> Self
+    {
+        const { assert!(PINS::WIDTH <= 16, "a parallel bus has at most 16 pins") };
+        ParallelBus { pins }
+    }
+
+    /// Drives the pins to the bits of `word`, bit 0 going to the first pin.
+    pub fn write(&mut self, word: u16)
+    {
+        unsafe {
+            Gpio::port(PINS::PORT).bsrr.write(self.pins.bsrr_bits(word));
+        }
+    }
+
+    /// Releases the pins of the bus.
+    pub fn release(self) -> PINS
+    {
+        self.pins
+    }
+}
@@ -0,0 +1,235 @@
+use embedded_hal::digital::InputPin;
+
+/// How a `Debounced` input decides that its level is stable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebounceMode
+{
+    /// The new level must be read on this many consecutive polls.
+    Samples(u8),
+    /// An integrator counts up on high samples and down on low ones (saturating at 0 and `max`);
+    /// the level switches to high when it reaches `max` and to low when it reaches 0.
+    Integrator { max: u8 },
+}
+
+/// Error returned by `Debounced::new()` when the sample count or `max` is 0, which
+/// would give a filter that never settles or settles on every sample.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DebounceModeError
+{
+    /// The rejected mode.
+    pub mode: DebounceMode,
+}
+
+/// Level at which the input is considered pressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActiveLevel
+{
+    Low,
+    High,
+}
+
+/// Edge reported by `Debounced::poll()` when the stable level changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebounceEvent
+{
+    /// The input switched to its active level.
+    Pressed,
+    /// The input switched back to its inactive level.
+    Released,
+}
+
+/// An input pin debounced in software.
+///
+/// `poll()` has to be called at a fixed rate, typically from a SysTick or timer
+/// interrupt; the debounce time is the poll period times the sample count (or `max`).
+/// The input starts in its released (inactive) state.
+///
+/// # Example
+/// ```
+/// let mut button = Debounced::new(gpioa.p0.into_input_pullup(), DebounceMode::Samples(5), ActiveLevel::Low)?;
+///
+/// // every millisecond
+/// if let Ok(Some(DebounceEvent::Pressed)) = button.poll() {
+///     // handle the press
+/// }
+/// ```
+pub struct Debounced<PIN>
+{
+    pin: PIN,
+    mode: DebounceMode,
+    active: ActiveLevel,
+    high: bool,
+    count: u8,
+}
+
+impl<PIN: InputPin> Debounced<PIN>
+{
+    /// Wraps `pin`, debouncing it with `mode`.
+    ///
+    /// # Errors
+    /// `DebounceModeError` if the sample count or `max` of `mode` is 0.
+    pub fn new(pin: PIN, mode: DebounceMode, active: ActiveLevel) -> Result<Self, DebounceModeError>
+    {
+        if let DebounceMode::Samples(0) | DebounceMode::Integrator { max: 0 } = mode {
+            return Err(DebounceModeError { mode });
+        }
+
+        let high = active == ActiveLevel::Low;
+        let count = match mode {
+            DebounceMode::Samples(_) => 0,
+            DebounceMode::Integrator { max } => if high { max } else { 0 },
+        };
+
+        Ok(Debounced { pin, mode, active, high, count })
+    }
+
+    /// Samples the pin once and updates the stable level.
+    /// 
+    /// # Returns
+    /// The press/release event if the stable level changed on this poll.
+    pub fn poll(&mut self) -> Result<Option<DebounceEvent>, PIN::Error>
+    {
+        let sample = self.pin.is_high()?;
+
+        let high = match self.mode {
+            DebounceMode::Samples(samples) => {
+                if sample == self.high {
+                    self.count = 0;
+                    self.high
+                } else {
+                    self.count = self.count.saturating_add(1);
+                    if self.count >= samples { sample } else { self.high }
+                }
+            }
+            DebounceMode::Integrator { max } => {
+                self.count = if sample {
+                    self.count.saturating_add(1).min(max)
+                } else {
+                    self.count.saturating_sub(1)
+                };
+
+                if self.count == max {
+                    true
+                } else if self.count == 0 {
+                    false
+                } else {
+                    self.high
+                }
+            }
+        };
+
+        if high == self.high {
+            return Ok(None);
+        }
+
+        self.high = high;
+        if let DebounceMode::Samples(_) = self.mode {
+            self.count = 0;
+        }
+
+        if self.is_pressed() {
+            Ok(Some(DebounceEvent::Pressed))
+        } else {
+            Ok(Some(DebounceEvent::Released))
+        }
+    }
+
+    /// Returns `true` if the stable level is high.
+    pub fn is_high(&self) -> bool
+    {
+        self.high
+    }
+
+    /// Returns `true` if the stable level is low.
+    pub fn is_low(&self) -> bool
+    {
+        !self.high
+    }
+
+    /// Returns `true` if the stable level is the active one.
+    pub fn is_pressed(&self) -> bool
+    {
+        self.high == (self.active == ActiveLevel::High)
+    }
+
+    /// Releases the wrapped pin.
+    pub fn release(self) -> PIN
+    {
+        self.pin
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use core::convert::Infallible;
+
+    /// Input pin replaying a fixed sequence of levels.
+    struct Samples<const L: usize>
+    {
+        levels: [bool; L],
+        index: usize,
+    }
+
+    impl<const L: usize> embedded_hal::digital::ErrorType for Samples<L>
+    {
+        type Error = Infallible;
+    }
+
+    impl<const L: usize> InputPin for Samples<L>
+    {
+        fn is_high(&mut self) -> Result<bool, Infallible>
+        {
+            let level = self.levels[self.index];
+            self.index += 1;
+            Ok(level)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Infallible>
+        {
+            self.is_high().map(|high| !high)
+        }
+    }
+
+    fn events<const L: usize>(levels: [bool; L], mode: DebounceMode, active: ActiveLevel) -> [Option<DebounceEvent>; L]
+    {
+        let mut input = Debounced::new(Samples { levels, index: 0 }, mode, active).unwrap();
+        core::array::from_fn(|_| input.poll().unwrap())
+    }
+
+    const H: bool = true;
+    const L: bool = false;
+    const PRESSED: Option<DebounceEvent> = Some(DebounceEvent::Pressed);
+    const RELEASED: Option<DebounceEvent> = Some(DebounceEvent::Released);
+
+    #[test]
+    fn zero_count_is_rejected()
+    {
+        let pin = Samples { levels: [H], index: 0 };
+        assert!(Debounced::new(pin, DebounceMode::Samples(0), ActiveLevel::Low).is_err());
+        let pin = Samples { levels: [H], index: 0 };
+        assert!(Debounced::new(pin, DebounceMode::Integrator { max: 0 }, ActiveLevel::Low).is_err());
+    }
+
+    #[test]
+    fn samples_need_consecutive_reads()
+    {
+        let got = events([L, L, H, L, L, L, H, H, H], DebounceMode::Samples(3), ActiveLevel::Low);
+        assert_eq!(got, [None, None, None, None, None, PRESSED, None, None, RELEASED]);
+    }
+
+    #[test]
+    fn integrator_switches_at_the_bounds()
+    {
+        let got = events([H, L, H, H, H, L, L, L, L], DebounceMode::Integrator { max: 3 }, ActiveLevel::High);
+        assert_eq!(got, [None, None, None, None, PRESSED, None, None, RELEASED, None]);
+    }
+
+    #[test]
+    fn single_sample_follows_the_input()
+    {
+        let got = events([H, L, L, H], DebounceMode::Samples(1), ActiveLevel::High);
+        assert_eq!(got, [PRESSED, RELEASED, None, PRESSED]);
+    }
+}
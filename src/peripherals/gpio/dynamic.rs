@@ -0,0 +1,218 @@
+use super::Gpio;
+use super::pin::{Floating, PinConfig, PinSpeed, PinState};
+
+/// The mode a `DynamicPin` is currently configured in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DynamicMode
+{
+    InputFloating,
+    InputPullUp,
+    InputPullDown,
+    OutputPushPull,
+    OutputOpenDrain,
+}
+
+/// Error returned when a `DynamicPin` is used in a mode that does not support the operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinModeError
+{
+    /// The mode the pin was in when the operation was attempted.
+    pub mode: DynamicMode,
+}
+
+impl embedded_hal::digital::Error for PinModeError
+{
+    fn kind(&self) -> embedded_hal::digital::ErrorKind
+    {
+        embedded_hal::digital::ErrorKind::Other
+    }
+}
+
+/// A pin whose mode is switched at runtime instead of through its type.
+///
+/// This suits protocols that turn the same line around many times per transaction
+/// (DHT22, 1-Wire, bit-banged I2C). Writing is only allowed in the output modes, and
+/// reading in the input modes and in open-drain output mode.
+///
+/// # Example
+/// ```
+/// let mut data = gpioa.p8.into_dynamic();
+/// data.make_output_opendrain(PinSpeed::Speed10Hz);
+/// data.set_low()?;
+/// data.make_input_pullup();
+/// let level = data.is_high()?;
+/// let pa8 = data.into_pin();
+/// ```
+pub struct DynamicPin<const P: char, const N: u8>
+{
+    mode: DynamicMode,
+}
+
+impl<const P: char, const N: u8, STATE: PinState> PinConfig<P, N, STATE>
+{
+    /// Turns the pin into a `DynamicPin`, starting as a floating input.
+    pub fn into_dynamic(self) -> DynamicPin<P, N>
+    {
+        let mut pin = DynamicPin { mode: DynamicMode::InputFloating };
+        pin.make_input_floating();
+        pin
+    }
+}
+
+impl<const P: char, const N: u8> DynamicPin<P, N>
+{
+    #[inline(always)]
+    fn gpio(&self) -> &'static Gpio
+    {
+        Gpio::port(P)
+    }
+
+    /// Returns the mode the pin is currently configured in.
+    pub fn mode(&self) -> DynamicMode
+    {
+        self.mode
+    }
+
+    /// Turns the pin back into a typed `PinConfig`, reconfigured as a floating input.
+    pub fn into_pin(mut self) -> PinConfig<P, N, Floating>
+    {
+        self.make_input_floating();
+        PinConfig::new()
+    }
+
+    /// Configures the pin as a push-pull output at the lowest speed, keeping the level in ODR.
+    ///
+    /// Use `make_output_pushpull()` or `make_output_opendrain()` to choose the speed or
+    /// the output type.
+    pub fn make_output(&mut self)
+    {
+        self.make_output_pushpull(PinSpeed::Speed2Hz);
+    }
+
+    /// Configures the pin as a push-pull output, keeping the level in ODR.
+    pub fn make_output_pushpull(&mut self, pin_speed: PinSpeed)
+    {
+        self.gpio().set_mode(N, 0b00, pin_speed as u32);
+        self.mode = DynamicMode::OutputPushPull;
+    }
+
+    /// Configures the pin as an open-drain output, keeping the level in ODR.
+    pub fn make_output_opendrain(&mut self, pin_speed: PinSpeed)
+    {
+        self.gpio().set_mode(N, 0b01, pin_speed as u32);
+        self.mode = DynamicMode::OutputOpenDrain;
+    }
+
+    /// Configures the pin as a floating input.
+    pub fn make_input_floating(&mut self)
+    {
+        self.gpio().set_mode(N, 0b01, 0b00);
+        self.mode = DynamicMode::InputFloating;
+    }
+
+    /// Configures the pin as an input with an internal pull-up resistor.
+    pub fn make_input_pullup(&mut self)
+    {
+        unsafe {
+            self.gpio().bsrr.write(1 << N);
+        }
+        self.gpio().set_mode(N, 0b10, 0b00);
+        self.mode = DynamicMode::InputPullUp;
+    }
+
+    /// Configures the pin as an input with an internal pull-down resistor.
+    pub fn make_input_pulldown(&mut self)
+    {
+        unsafe {
+            self.gpio().brr.write(1 << N);
+        }
+        self.gpio().set_mode(N, 0b10, 0b00);
+        self.mode = DynamicMode::InputPullDown;
+    }
+
+    /// Sets the pin to a high state (logical 1).
+    /// 
+    /// # Errors
+    /// `PinModeError` if the pin is not configured as an output.
+    pub fn set_high(&mut self) -> Result<(), PinModeError>
+    {
+        match self.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                unsafe {
+                    self.gpio().bsrr.write(1 << N);
+                }
+                Ok(())
+            }
+            mode => Err(PinModeError { mode }),
+        }
+    }
+
+    /// Resets the pin to a low state (logical 0).
+    /// 
+    /// # Errors
+    /// `PinModeError` if the pin is not configured as an output.
+    pub fn set_low(&mut self) -> Result<(), PinModeError>
+    {
+        match self.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                unsafe {
+                    self.gpio().brr.write(1 << N);
+                }
+                Ok(())
+            }
+            mode => Err(PinModeError { mode }),
+        }
+    }
+
+    /// Checks if the line is in a high state (logical 1), by reading the IDR register.
+    /// 
+    /// # Errors
+    /// `PinModeError` if the pin is configured as a push-pull output.
+    pub fn is_high(&self) -> Result<bool, PinModeError>
+    {
+        match self.mode {
+            DynamicMode::OutputPushPull => Err(PinModeError { mode: self.mode }),
+            _ => Ok((self.gpio().idr.read() & (1 << N)) != 0),
+        }
+    }
+
+    /// Checks if the line is in a low state (logical 0), by reading the IDR register.
+    /// 
+    /// # Errors
+    /// `PinModeError` if the pin is configured as a push-pull output.
+    pub fn is_low(&self) -> Result<bool, PinModeError>
+    {
+        self.is_high().map(|high| !high)
+    }
+}
+
+impl<const P: char, const N: u8> embedded_hal::digital::ErrorType for DynamicPin<P, N>
+{
+    type Error = PinModeError;
+}
+
+impl<const P: char, const N: u8> embedded_hal::digital::OutputPin for DynamicPin<P, N>
+{
+    fn set_high(&mut self) -> Result<(), Self::Error>
+    {
+        DynamicPin::set_high(self)
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error>
+    {
+        DynamicPin::set_low(self)
+    }
+}
+
+impl<const P: char, const N: u8> embedded_hal::digital::InputPin for DynamicPin<P, N>
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error>
+    {
+        DynamicPin::is_high(self)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error>
+    {
+        DynamicPin::is_low(self)
+    }
+}
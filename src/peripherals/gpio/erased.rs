@@ -0,0 +1,226 @@
+use core::marker::PhantomData;
+
+use super::Gpio;
+use super::pin::{Floating, InputPullDown, InputPullUp, InputState, OutputState, PinConfig};
+
+/// A pin whose port is still part of the type but whose number is only known at runtime.
+///
+/// All the pins of one port in the same state share this type, so they can be stored in an array.
+pub struct PartiallyErasedPin<const P: char, STATE>
+{
+    pin: u8,
+    _state: PhantomData<STATE>,
+}
+
+/// A pin whose port and number are both only known at runtime.
+///
+/// All the pins in the same state share this type, whatever their port, so they can be
+/// stored in an array or a slice and iterated.
+pub struct ErasedPin<STATE>
+{
+    port: char,
+    pin: u8,
+    _state: PhantomData<STATE>,
+}
+
+impl<const P: char, const N: u8, STATE> PinConfig<P, N, STATE>
+{
+    /// Erases the pin number from the type.
+    ///
+    /// # Example
+    /// ```
+    /// let leds = [
+    ///     gpioa.p0.into_output_pushpull(PinSpeed::Speed2Hz).erase_number(),
+    ///     gpioa.p1.into_output_pushpull(PinSpeed::Speed2Hz).erase_number(),
+    /// ];
+    /// ```
+    pub fn erase_number(self) -> PartiallyErasedPin<P, STATE>
+    {
+        PartiallyErasedPin {
+            pin: N,
+            _state: PhantomData,
+        }
+    }
+
+    /// Erases both the port and the pin number from the type.
+    ///
+    /// # Example
+    /// ```
+    /// let rows = [
+    ///     gpioa.p0.into_input_pullup().erase(),
+    ///     gpiob.p12.into_input_pullup().erase(),
+    /// ];
+    /// ```
+    pub fn erase(self) -> ErasedPin<STATE>
+    {
+        ErasedPin {
+            port: P,
+            pin: N,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<const P: char, STATE> PartiallyErasedPin<P, STATE>
+{
+    /// Returns the pin number.
+    pub fn pin_number(&self) -> u8
+    {
+        self.pin
+    }
+
+    /// Erases the port from the type as well.
+    pub fn erase(self) -> ErasedPin<STATE>
+    {
+        ErasedPin {
+            port: P,
+            pin: self.pin,
+            _state: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn gpio(&self) -> &'static Gpio
+    {
+        Gpio::port(P)
+    }
+}
+
+impl<STATE> ErasedPin<STATE>
+{
+    /// Returns the port letter (`'A'`..`'G'`).
+    pub fn port(&self) -> char
+    {
+        self.port
+    }
+
+    /// Returns the pin number.
+    pub fn pin_number(&self) -> u8
+    {
+        self.pin
+    }
+
+    #[inline(always)]
+    fn gpio(&self) -> &'static Gpio
+    {
+        Gpio::port(self.port)
+    }
+}
+
+macro_rules! erased_pin {
+    ($Pin: ident, [$($gen: tt)*], [$($arg: tt)*]) => {
+        impl<$($gen)* STATE: OutputState> $Pin<$($arg)* STATE>
+        {
+            /// Sets the pin to a high state (logical 1) through the BSRR register.
+            pub fn set(&self)
+            {
+                unsafe {
+                    self.gpio().bsrr.write(1 << self.pin);
+                }
+            }
+
+            /// Resets the pin to a low state (logical 0) through the BRR register.
+            pub fn reset(&self)
+            {
+                unsafe {
+                    self.gpio().brr.write(1 << self.pin);
+                }
+            }
+
+            /// Inverts the level driven by the pin with a single BSRR write.
+            pub fn toggle(&self)
+            {
+                self.gpio().toggle_masked(1 << self.pin);
+            }
+
+            /// Checks if the pin is driven high, by reading the ODR register.
+            pub fn is_set_high(&self) -> bool
+            {
+                (self.gpio().odr.read() & (1 << self.pin)) != 0
+            }
+
+            /// Checks if the pin is driven low, by reading the ODR register.
+            pub fn is_set_low(&self) -> bool
+            {
+                (self.gpio().odr.read() & (1 << self.pin)) == 0
+            }
+        }
+
+        impl<$($gen)* STATE: InputState> $Pin<$($arg)* STATE>
+        {
+            /// Checks if the input pin is in a high state (logical 1) by reading the IDR register.
+            pub fn is_high(&self) -> bool
+            {
+                (self.gpio().idr.read() & (1 << self.pin)) != 0
+            }
+
+            /// Checks if the input pin is in a low state (logical 0) by reading the IDR register.
+            pub fn is_low(&self) -> bool
+            {
+                (self.gpio().idr.read() & (1 << self.pin)) == 0
+            }
+        }
+
+        impl<$($gen)* STATE> embedded_hal::digital::ErrorType for $Pin<$($arg)* STATE>
+        {
+            type Error = core::convert::Infallible;
+        }
+
+        impl<$($gen)* STATE: OutputState> embedded_hal::digital::OutputPin for $Pin<$($arg)* STATE>
+        {
+            fn set_high(&mut self) -> Result<(), Self::Error>
+            {
+                self.set();
+                Ok(())
+            }
+
+            fn set_low(&mut self) -> Result<(), Self::Error>
+            {
+                self.reset();
+                Ok(())
+            }
+        }
+
+        impl<$($gen)* STATE: OutputState> embedded_hal::digital::StatefulOutputPin for $Pin<$($arg)* STATE>
+        {
+            fn is_set_high(&mut self) -> Result<bool, Self::Error>
+            {
+                Ok($Pin::is_set_high(self))
+            }
+
+            fn is_set_low(&mut self) -> Result<bool, Self::Error>
+            {
+                Ok($Pin::is_set_low(self))
+            }
+
+            fn toggle(&mut self) -> Result<(), Self::Error>
+            {
+                $Pin::toggle(self);
+                Ok(())
+            }
+        }
+
+        erased_pin!(@input $Pin, [$($gen)*], [$($arg)*], Floating);
+        erased_pin!(@input $Pin, [$($gen)*], [$($arg)*], InputPullUp);
+        erased_pin!(@input $Pin, [$($gen)*], [$($arg)*], InputPullDown);
+    };
+
+    // Analog pins are left out, like for `PinConfig`.
+    (@input $Pin: ident, [$($gen: tt)*], [$($arg: tt)*], $state: ty) => {
+        impl<$($gen)*> embedded_hal::digital::InputPin for $Pin<$($arg)* $state>
+        {
+            fn is_high(&mut self) -> Result<bool, Self::Error>
+            {
+                Ok($Pin::is_high(self))
+            }
+
+            fn is_low(&mut self) -> Result<bool, Self::Error>
+            {
+                Ok($Pin::is_low(self))
+            }
+        }
+    };
+}
+
+erased_pin!(PartiallyErasedPin, [const P: char,], [P,]);
+erased_pin!(ErasedPin, [], []);
@@ -0,0 +1,86 @@
+use super::pin::{Locked, PinConfig, PinState};
+use super::{LockError, LockedPins, Port};
+
+/// A group of pins of port `P` that can be locked with a single LCKR sequence.
+///
+/// Implemented for tuples of up to 16 `PinConfig` pins of the same port.
+pub trait LockPins<const P: char>
+{
+    /// The same pins in the `Locked` state, keeping their previous state.
+    type Locked;
+
+    /// Returns the LCKR mask of the pins.
+    fn mask(&self) -> u16;
+
+    /// Turns the pins into their `Locked` state.
+    fn into_locked(self) -> Self::Locked;
+}
+
+macro_rules! lock_pins {
+    ($($S: ident: $N: ident),+) => {
+        impl<const P: char, $(const $N: u8, $S: PinState),+> LockPins<P> for ($(PinConfig<P, $N, $S>,)+)
+        {
+            type Locked = ($(PinConfig<P, $N, Locked<$S>>,)+);
+
+            fn mask(&self) -> u16
+            {
+                0 $(| (1 << $N))+
+            }
+
+            fn into_locked(self) -> Self::Locked
+            {
+                ($(PinConfig::<P, $N, Locked<$S>>::new(),)+)
+            }
+        }
+    }
+}
+
+lock_pins!(S0: N0);
+lock_pins!(S0: N0, S1: N1);
+lock_pins!(S0: N0, S1: N1, S2: N2);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10, S11: N11);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10, S11: N11, S12: N12);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10, S11: N11, S12: N12, S13: N13);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10, S11: N11, S12: N12, S13: N13, S14: N14);
+lock_pins!(S0: N0, S1: N1, S2: N2, S3: N3, S4: N4, S5: N5, S6: N6, S7: N7, S8: N8, S9: N9, S10: N10, S11: N11, S12: N12, S13: N13, S14: N14, S15: N15);
+
+impl<const P: char> Port<P>
+{
+    /// Locks the configuration of `pins` until the next reset, in one LCKR sequence.
+    ///
+    /// The pins are taken and returned in the `Locked` state, so their mode can no
+    /// longer be changed in software either, while outputs can still be driven and
+    /// inputs read. A port can only be locked once per reset, so all the pins to lock
+    /// must be passed together.
+    ///
+    /// # Returns
+    /// The `LockedPins` on success, or a `LockError` together with the unchanged pins if
+    /// the port was already locked or LCKK does not read back as set.
+    ///
+    /// # Example
+    /// ```
+    /// let pa0 = gpioa.p0.into_output_pushpull(PinSpeed::Speed2Hz);
+    /// let pa1 = gpioa.p1.into_input_pullup();
+    /// let locked = gpioa.port.lock((pa0, pa1)).map_err(|(error, _)| error)?;
+    /// assert!(locked.is_locked(1));
+    /// let (pa0, pa1) = locked.release();
+    /// pa0.set();
+    /// ```
+    pub fn lock<PINS: LockPins<P>>(&self, pins: PINS) -> Result<LockedPins<PINS::Locked>, (LockError, PINS)>
+    {
+        let mask = pins.mask();
+        if let Err(error) = self.lock_mask(mask) {
+            return Err((error, pins));
+        }
+
+        Ok(LockedPins { mask, pins: pins.into_locked() })
+    }
+}
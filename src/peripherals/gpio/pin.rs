@@ -3,11 +3,26 @@ use core::marker::PhantomData;
 use super::Gpio;
 
 pub struct Disabled;
-pub struct Locked;
+
+/// State of a pin whose configuration was locked in `STATE` by `Port::lock()`.
+///
+/// LCKR only freezes CRL/CRH, so the output and input methods of `STATE` stay
+/// available; the mode transitions do not.
+pub struct Locked<STATE>
+{
+    _state: PhantomData<STATE>,
+}
+
+/// State of the pins used by the SWJ debug port after reset (PA13, PA14, PA15, PB3, PB4).
+/// 
+/// They cannot be configured until the debug port is reconfigured through the
+/// `afio::Parts` handle, which only exists once the AFIO clock is on.
+pub struct Debugger;
 
 pub trait PinState {}
 pub trait OutputState {}
 pub trait InputState {}
+pub trait AlternateState {}
 
 pub struct PushPull;
 pub struct OpenDrain;
@@ -18,6 +33,15 @@ impl OutputState for OpenDrain {}
 impl PinState for PushPull {}
 impl PinState for OpenDrain {}
 
+pub struct AlternatePushPull;
+pub struct AlternateOpenDrain;
+
+impl AlternateState for AlternatePushPull {}
+impl AlternateState for AlternateOpenDrain {}
+
+impl PinState for AlternatePushPull {}
+impl PinState for AlternateOpenDrain {}
+
 pub struct Analog;
 pub struct Floating; 
 pub struct InputPullUp; 
@@ -28,11 +52,31 @@ impl InputState for Floating {}
 impl InputState for InputPullUp {}
 impl InputState for InputPullDown {}
 
+/// Input states a peripheral input (USART RX, SPI MISO, CAN RX) can be read from.
+pub trait AlternateInputState: InputState {}
+
+impl AlternateInputState for Floating {}
+impl AlternateInputState for InputPullUp {}
+
+/// Input states with the Schmitt trigger connected, whose level reaches IDR and EXTI.
+/// `Analog` is left out.
+pub trait DigitalInputState: InputState {}
+
+impl DigitalInputState for Floating {}
+impl DigitalInputState for InputPullUp {}
+impl DigitalInputState for InputPullDown {}
+
 impl PinState for Analog {}
 impl PinState for Floating {}
 impl PinState for InputPullUp {}
 impl PinState for InputPullDown {}
 
+impl PinState for Disabled {}
+
+impl<STATE: OutputState> OutputState for Locked<STATE> {}
+impl<STATE: InputState> InputState for Locked<STATE> {}
+impl<STATE: DigitalInputState> DigitalInputState for Locked<STATE> {}
+
 /*
     01: Output mode, max speed 10 MHz.
     10: Output mode, max speed 2 MHz.
@@ -45,75 +89,80 @@ pub enum PinSpeed
     Speed50Hz = 0b11,
 }
 
-pub struct PinConfig<'a,const N: u8, STATE>
+/// A GPIO pin, identified at the type level by its port letter `P` (`'A'`..`'G'`)
+/// and its pin number `N`, in the configuration `STATE`.
+pub struct PinConfig<const P: char, const N: u8, STATE>
 {
-    gpio: &'a Gpio,
     _state: core::marker::PhantomData<STATE>,
 }
 
-/// Creates a new `PinConfig` for a specified GPIO pin in a disabled state.
+/// Creates a new `PinConfig` for a specified GPIO pin in the state `STATE`.
 /// 
-/// # Arguments
-/// * `gpio` - Reference to the `Gpio` struct representing the GPIO port.
+/// Pins are handed out once by the port's `split()`, so this stays crate-private.
 ///
 /// # Returns
-/// A new `PinConfig` object for pin `N` in a `Disabled` state.
-impl <'a, const N:u8, STATE> PinConfig<'a, N, STATE>
+/// A new `PinConfig` object for pin `N` of port `P`, normally in the `Disabled` state.
+impl<const P: char, const N: u8, STATE> PinConfig<P, N, STATE>
 {
-    pub fn new(gpio: &'a Gpio) -> PinConfig<N, Disabled>
+    pub(crate) fn new() -> Self
     {
-        PinConfig::<N, Disabled>{
-            gpio,
+        PinConfig {
             _state: PhantomData,
         }
     }
+
+    /// Returns the register block of the port this pin belongs to.
+    #[inline(always)]
+    fn gpio(&self) -> &'static Gpio
+    {
+        Gpio::port(P)
+    }
 }
 
-/// Configures the pin as an output in push-pull mode with a specified speed.
-/// 
-/// # Arguments
-/// * `pin_speed` - The speed mode for the output pin (`Speed2Hz`, `Speed10Hz`, `Speed50Hz`).
-/// 
-/// # Returns
-/// A new `PinConfig` object for pin `N` in `PushPull` output state.
-///
-/// # Example
-/// ```
-/// let pin = gpio.p0().into_output_pushpull(PinSpeed::Speed50Hz);
-/// ```
-impl<'a, const N:u8> PinConfig<'a, N, Disabled>
+impl<const P: char, const N: u8, STATE> PinConfig<P, N, STATE>
 {
-   pub fn into_output_pushpull(&self, pin_speed:PinSpeed) -> PinConfig<'a, N, PushPull>
-   {   
-        let cnf = 0b00;
-        let mode = pin_speed as u32;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
+    fn set_mode(&self, cnf: u32, mode: u32)
+    {
+        self.gpio().set_mode(N, cnf, mode);
+    }
 
-            }
-            _ => ()
-        }
-        PinConfig::<N, PushPull> {
-            gpio: self.gpio, 
+    fn into_state<NEW>(self) -> PinConfig<P, N, NEW>
+    {
+        PinConfig::<P, N, NEW> {
             _state: PhantomData,
         }
+    }
+}
+
+/// Mode transitions, available from `Disabled` and from every configured `PinState`.
+/// 
+/// An output can be turned into an input (or into another output mode) directly, without
+/// going through `disable()`. Switching between output modes leaves ODR untouched, so the
+/// pin keeps driving its current level. Switching from a pulled input to an output drives
+/// the level of the previous pull (pull-up: high, pull-down: low).
+///
+/// A pulled input gets its pull level written to ODR (through BSRR/BRR) before CRL/CRH
+/// switch the mode, so it never goes through the opposite pull. The same order is used
+/// by `Batch::commit()`. As a result, an output switched to a pulled input drives the
+/// new pull level for the few cycles until the mode write lands.
+impl<const P: char, const N: u8, STATE: PinState> PinConfig<P, N, STATE>
+{
+   /// Configures the pin as an output in push-pull mode with a specified speed.
+   /// 
+   /// # Arguments
+   /// * `pin_speed` - The speed mode for the output pin (`Speed2Hz`, `Speed10Hz`, `Speed50Hz`).
+   /// 
+   /// # Returns
+   /// A new `PinConfig` object for pin `N` in `PushPull` output state.
+   ///
+   /// # Example
+   /// ```
+   /// let pin = gpioa.p0.into_output_pushpull(PinSpeed::Speed50Hz);
+   /// ```
+   pub fn into_output_pushpull(self, pin_speed:PinSpeed) -> PinConfig<P, N, PushPull>
+   {   
+        self.set_mode(0b00, pin_speed as u32);
+        self.into_state()
    }
 
    /// Configures the pin as an output in open-drain mode with a specified speed.
@@ -126,40 +175,54 @@ impl<'a, const N:u8> PinConfig<'a, N, Disabled>
    ///
    /// # Example
    /// ```
-   /// let pin = gpio.p1().into_output_opendrain(PinSpeed::Speed2Hz);
+   /// let pin = gpioa.p1.into_output_opendrain(PinSpeed::Speed2Hz);
    /// ```
-   pub fn into_output_opendrain(&self, pin_speed:PinSpeed) -> PinConfig<'a, N, OpenDrain>
+   pub fn into_output_opendrain(self, pin_speed:PinSpeed) -> PinConfig<P, N, OpenDrain>
    {    
-        let cnf = 0b01;
-        let mode = pin_speed as u32;
-        let bits = (cnf << 2) | mode;
+        self.set_mode(0b01, pin_speed as u32);
+        self.into_state()
+   } 
 
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
+   /// Configures the pin as an alternate function output in push-pull mode with a specified speed.
+   ///
+   /// The pin is driven by the peripheral it is mapped to (USART TX, SPI SCK/MOSI, timer PWM, ...)
+   /// instead of the ODR register.
+   /// 
+   /// # Arguments
+   /// * `pin_speed` - The speed mode for the output pin (`Speed2Hz`, `Speed10Hz`, `Speed50Hz`).
+   /// 
+   /// # Returns
+   /// A new `PinConfig` object for pin `N` in `AlternatePushPull` state.
+   ///
+   /// # Example
+   /// ```
+   /// let tx = gpioa.p9.into_alternate_pushpull(PinSpeed::Speed50Hz);
+   /// ```
+   pub fn into_alternate_pushpull(self, pin_speed:PinSpeed) -> PinConfig<P, N, AlternatePushPull>
+   {
+        self.set_mode(0b10, pin_speed as u32);
+        self.into_state()
+   }
 
-            }
-            _ => ()
-        }
-        PinConfig::<N, OpenDrain> {
-            gpio: self.gpio, 
-            _state: PhantomData,
-        }
-   } 
+   /// Configures the pin as an alternate function output in open-drain mode with a specified speed.
+   ///
+   /// This is the mode required by I2C SCL/SDA lines.
+   /// 
+   /// # Arguments
+   /// * `pin_speed` - The speed mode for the output pin (`Speed2Hz`, `Speed10Hz`, `Speed50Hz`).
+   /// 
+   /// # Returns
+   /// A new `PinConfig` object for pin `N` in `AlternateOpenDrain` state.
+   ///
+   /// # Example
+   /// ```
+   /// let scl = gpiob.p6.into_alternate_opendrain(PinSpeed::Speed50Hz);
+   /// ```
+   pub fn into_alternate_opendrain(self, pin_speed:PinSpeed) -> PinConfig<P, N, AlternateOpenDrain>
+   {
+        self.set_mode(0b11, pin_speed as u32);
+        self.into_state()
+   }
 
    /// Configures the pin as an analog input.
    /// 
@@ -168,39 +231,12 @@ impl<'a, const N:u8> PinConfig<'a, N, Disabled>
    ///
    /// # Example
    /// ```
-   /// let pin = gpio.p2().into_analog();
+   /// let pin = gpioa.p2.into_analog();
    /// ```
-   pub fn into_analog(&self) -> PinConfig<'a, N, Analog>
+   pub fn into_analog(self) -> PinConfig<P, N, Analog>
    {    
-        let cnf = 0b00;
-        let mode = 0b00;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-
-            }
-            _ => ()
-        }
-        PinConfig::<N, Analog> {
-            gpio: self.gpio, 
-            _state: PhantomData,
-        }
+        self.set_mode(0b00, 0b00);
+        self.into_state()
    }
 
    /// Configures the pin as an input with a floating state (no pull-up or pull-down resistor).
@@ -210,39 +246,12 @@ impl<'a, const N:u8> PinConfig<'a, N, Disabled>
    ///
    /// # Example
    /// ```
-   /// let pin = gpio.p3().into_input_floating();
+   /// let pin = gpioa.p3.into_input_floating();
    /// ```
-   pub fn into_input_floating(&self) -> PinConfig<'a, N, Floating>
+   pub fn into_input_floating(self) -> PinConfig<P, N, Floating>
    {    
-        let cnf = 0b01;
-        let mode = 0b00;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-
-            }
-            _ => ()
-        }
-        PinConfig::<N, Floating> {
-            gpio: self.gpio, 
-            _state: PhantomData,
-        }
+        self.set_mode(0b01, 0b00);
+        self.into_state()
    } 
 
    /// Configures the pin as an input with an internal pull-up resistor.
@@ -252,44 +261,17 @@ impl<'a, const N:u8> PinConfig<'a, N, Disabled>
    ///
    /// # Example
    /// ```
-   /// let pin = gpio.p4().into_input_pullup();
+   /// let pin = gpioa.p4.into_input_pullup();
    /// ```
-   pub fn into_input_pullup(&self) -> PinConfig<'a, N, InputPullUp>
+   pub fn into_input_pullup(self) -> PinConfig<P, N, InputPullUp>
    {    
-        let cnf = 0b10;
-        let mode = 0b00;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-
-            }
-            _ => ()
-        }
-
+        // the pull direction is selected by the ODR bit, set it through BSRR
         unsafe {
-            self.gpio.odr.modify(|r| r | (1 << N));
+            self.gpio().bsrr.write(1 << N);
         }
 
-        PinConfig::<N, InputPullUp> {
-            gpio: self.gpio, 
-            _state: PhantomData,
-        }
+        self.set_mode(0b10, 0b00);
+        self.into_state()
    } 
 
    /// Configures the pin as an input with an internal pull-down resistor.
@@ -299,49 +281,21 @@ impl<'a, const N:u8> PinConfig<'a, N, Disabled>
    ///
    /// # Example
    /// ```
-   /// let pin = gpio.p5().into_input_pulldown();
+   /// let pin = gpioa.p5.into_input_pulldown();
    /// ```
-   pub fn into_input_pulldown(&self) -> PinConfig<'a, N, InputPullDown>
+   pub fn into_input_pulldown(self) -> PinConfig<P, N, InputPullDown>
    {    
-        let cnf = 0b10;
-        let mode = 0b00;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-
-            }
-            _ => ()
-        }
-
+        // the pull direction is selected by the ODR bit, clear it through BRR
         unsafe {
-            self.gpio.odr.modify(|r| r & !(1 << N));
+            self.gpio().brr.write(1 << N);
         }
 
-        PinConfig::<N, InputPullDown> {
-            gpio: self.gpio, 
-            _state: PhantomData,
-        }
+        self.set_mode(0b10, 0b00);
+        self.into_state()
    } 
 }
 
-
-impl<'a, const N:u8, STATE: OutputState> PinConfig<'a, N, STATE>
+impl<const P: char, const N: u8, STATE: OutputState> PinConfig<P, N, STATE>
 {
     /// Sets the pin to a high state (logical 1).
     /// 
@@ -349,13 +303,13 @@ impl<'a, const N:u8, STATE: OutputState> PinConfig<'a, N, STATE>
     /// 
     /// # Example
     /// ```
-    /// let pin = gpio.p6().into_output_pushpull(PinSpeed::Speed50Hz);
+    /// let pin = gpioa.p6.into_output_pushpull(PinSpeed::Speed50Hz);
     /// pin.set();
     /// ```
     pub fn set(&self) 
     {
         unsafe {
-          self.gpio.bsrr.write(1 << N); 
+          self.gpio().bsrr.write(1 << N); 
         }    
     }
 
@@ -365,18 +319,51 @@ impl<'a, const N:u8, STATE: OutputState> PinConfig<'a, N, STATE>
     /// 
     /// # Example
     /// ```
-    /// let pin = gpio.p7().into_output_pushpull(PinSpeed::Speed50Hz);
+    /// let pin = gpioa.p7.into_output_pushpull(PinSpeed::Speed50Hz);
     /// pin.reset();
     /// ```
     pub fn reset(&self) 
     {
         unsafe {
-          self.gpio.brr.write(1 << N); 
+          self.gpio().brr.write(1 << N); 
         }    
     }
+
+    /// Inverts the level driven by the pin.
+    /// 
+    /// The current level is read from the ODR register and the opposite one is
+    /// written through BSRR, so other pins of the port are never touched.
+    /// 
+    /// # Example
+    /// ```
+    /// let pin = gpioa.p1.into_output_pushpull(PinSpeed::Speed2Hz);
+    /// pin.toggle();
+    /// ```
+    pub fn toggle(&self) 
+    {
+        self.gpio().toggle_masked(1 << N);
+    }
+
+    /// Checks if the pin is driven high, by reading the ODR register.
+    /// 
+    /// # Returns
+    /// `true` if the output is set high, `false` otherwise.
+    pub fn is_set_high(&self) -> bool
+    {
+        (self.gpio().odr.read() & (1 << N)) != 0
+    }
+
+    /// Checks if the pin is driven low, by reading the ODR register.
+    /// 
+    /// # Returns
+    /// `true` if the output is set low, `false` otherwise.
+    pub fn is_set_low(&self) -> bool
+    {
+        (self.gpio().odr.read() & (1 << N)) == 0
+    }
 }
 
-impl<'a, const N:u8, STATE: InputState> PinConfig<'a, N, STATE>
+impl<const P: char, const N: u8, STATE: InputState> PinConfig<P, N, STATE>
 {
     /// Checks if the input pin is in a high state (logical 1).
     /// 
@@ -387,14 +374,14 @@ impl<'a, const N:u8, STATE: InputState> PinConfig<'a, N, STATE>
     ///
     /// # Example
     /// ```
-    /// let pin = gpio.p8().into_input_floating();
+    /// let pin = gpioa.p8.into_input_floating();
     /// if pin.is_high() {
     ///     // Do something
     /// }
     /// ```
     pub fn is_high(&self) -> bool
     {
-        let r = self.gpio.idr.read(); 
+        let r = self.gpio().idr.read(); 
         (r & (1 << N)) != 0
     }
 
@@ -407,63 +394,54 @@ impl<'a, const N:u8, STATE: InputState> PinConfig<'a, N, STATE>
     ///
     /// # Example
     /// ```
-    /// let pin = gpio.p9().into_input_floating();
+    /// let pin = gpioa.p9.into_input_floating();
     /// if pin.is_low() {
     ///     // Do something
     /// }
     /// ```
     pub fn is_low(&self) -> bool
     {
-        let r = self.gpio.idr.read(); 
+        let r = self.gpio().idr.read(); 
         (r & (1 << N)) == 0
     }
 }
 
-impl<'a, const N: u8, STATE: PinState> PinConfig<'a, N, STATE>
-{
-    /// Locks the configuration of the pin, preventing further modifications.
-    /// 
-    /// This follows the lock sequence as described in the hardware manual, which requires a series of writes and reads to the LCKR register.
-    /// 
-    /// # Returns
-    /// A new `PinConfig` object for pin `N` in the `Locked` state.
-    ///
-    /// # Example
-    /// ```
-    /// let pin = gpio.p10().into_output_pushpull(PinSpeed::Speed50Hz);
-    /// let locked_pin = pin.lock();
-    /// ```
-    pub fn lock(&self) -> PinConfig<'a, N, Locked>
-    {
-        unsafe {
-           let f = self.gpio.lckr.read() | 1;
-           self.gpio.lckr.write(f);
-           //self.gpio.lckr.modify(|r| r | (1 << 0)); 
-        }
-        /*
-         * lock sequence:
-         * -------------
-         * write 1 
-         * write 0 
-         * write 1 
-         * read 0 
-         * read 1 (this read is optional but confirms that the lock is active)
-         */
-        unsafe {
-            self.gpio.lckr.modify(|r| r | (1 << 16)); 
-            self.gpio.lckr.modify(|r| r & !(1 << 16)); 
-            self.gpio.lckr.modify(|r| r | (1 << 16)); 
-            self.gpio.lckr.read(); 
-            self.gpio.lckr.read(); 
-        }
+macro_rules! open_drain_input {
+    ($($state: ty),+) => {
+        $(
+            impl<const P: char, const N: u8> PinConfig<P, N, $state>
+            {
+                /// Checks if the open-drain line is in a high state (logical 1).
+                /// 
+                /// The input buffer stays connected in open-drain output mode, so the IDR register
+                /// reflects the actual line level, which another device may be pulling low.
+                /// 
+                /// # Returns
+                /// `true` if the line is high, `false` otherwise.
+                pub fn is_high(&self) -> bool
+                {
+                    let r = self.gpio().idr.read(); 
+                    (r & (1 << N)) != 0
+                }
 
-        PinConfig::<N, Locked>
-        {
-            gpio: self.gpio,
-            _state: PhantomData,
-        }
+                /// Checks if the open-drain line is in a low state (logical 0), by reading the IDR register.
+                /// 
+                /// # Returns
+                /// `true` if the line is low, `false` otherwise.
+                pub fn is_low(&self) -> bool
+                {
+                    let r = self.gpio().idr.read(); 
+                    (r & (1 << N)) == 0
+                }
+            }
+        )+
     }
+}
+
+open_drain_input!(OpenDrain, Locked<OpenDrain>);
 
+impl<const P: char, const N: u8, STATE: PinState> PinConfig<P, N, STATE>
+{
     /// Disables the pin, returning it to a default state.
     /// 
     /// # Returns
@@ -471,48 +449,82 @@ impl<'a, const N: u8, STATE: PinState> PinConfig<'a, N, STATE>
     ///
     /// # Example
     /// ```
-    /// let pin = gpio.p11().into_output_pushpull(PinSpeed::Speed50Hz);
+    /// let pin = gpioa.p11.into_output_pushpull(PinSpeed::Speed50Hz);
     /// let disabled_pin = pin.disable();
     /// ```
-    pub fn disable(&self) -> PinConfig<'a, N, Disabled>
+    pub fn disable(self) -> PinConfig<P, N, Disabled>
     {
-        let cnf = 0b01;
-        let mode = 0b00;
-        let bits = (cnf << 2) | mode;
-
-        match N 
-        {
-            0..=7 => {
-                unsafe {    
-                    self.gpio.crl.modify(|r|{
-                        let offset = N * 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-            }
-            8..=15 => {
-                unsafe {    
-                    self.gpio.crh.modify(|r|{
-                        let offset = (N - 8)* 4;
-                        r & !(0b1111 << offset) | (bits << offset)
-                    })
-                }
-
-            }
-            _ => ()
-        }
+        self.set_mode(0b01, 0b00);
 
         unsafe 
         {
-            self.gpio.brr.write(1 << N);
+            self.gpio().brr.write(1 << N);
         }
 
-        PinConfig::<N, Disabled>
-        {
-            gpio: self.gpio,
-            _state: PhantomData,
-        }
+        self.into_state()
     }
 
 }
 
+impl<const P: char, const N: u8, STATE> embedded_hal::digital::ErrorType for PinConfig<P, N, STATE>
+{
+    type Error = core::convert::Infallible;
+}
+
+impl<const P: char, const N: u8, STATE: OutputState> embedded_hal::digital::OutputPin for PinConfig<P, N, STATE>
+{
+    fn set_high(&mut self) -> Result<(), Self::Error>
+    {
+        self.set();
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error>
+    {
+        self.reset();
+        Ok(())
+    }
+}
+
+impl<const P: char, const N: u8, STATE: OutputState> embedded_hal::digital::StatefulOutputPin for PinConfig<P, N, STATE>
+{
+    fn is_set_high(&mut self) -> Result<bool, Self::Error>
+    {
+        Ok(PinConfig::is_set_high(self))
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error>
+    {
+        Ok(PinConfig::is_set_low(self))
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error>
+    {
+        PinConfig::toggle(self);
+        Ok(())
+    }
+}
+
+// Analog pins are left out: the Schmitt trigger is disconnected and IDR always reads 0.
+// Open-drain outputs are included, their input buffer stays connected.
+macro_rules! input_pin {
+    ($($state: ty),+) => {
+        $(
+            impl<const P: char, const N: u8> embedded_hal::digital::InputPin for PinConfig<P, N, $state>
+            {
+                fn is_high(&mut self) -> Result<bool, Self::Error>
+                {
+                    Ok(PinConfig::<P, N, $state>::is_high(self))
+                }
+
+                fn is_low(&mut self) -> Result<bool, Self::Error>
+                {
+                    Ok(PinConfig::<P, N, $state>::is_low(self))
+                }
+            }
+        )+
+    }
+}
+
+input_pin!(Floating, InputPullUp, InputPullDown, OpenDrain);
+input_pin!(Locked<Floating>, Locked<InputPullUp>, Locked<InputPullDown>, Locked<OpenDrain>);
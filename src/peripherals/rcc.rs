@@ -1,5 +1,25 @@
 use volatile_register::RW;
 
+use super::flash::{Flash, Latency};
+
+/// Generates `reset_*` methods pulsing a bit of an APB reset register.
+macro_rules! reset {
+    ($rstr:ident: [$(($name:ident, $bit:expr, $periph:expr),)+]) => {
+        $(
+            #[doc = concat!("Resets ", $periph, " by pulsing its bit in ", stringify!($rstr), ".")]
+            ///
+            /// All the registers of the peripheral return to their reset values.
+            #[inline(always)]
+            pub fn $name(&self) {
+                unsafe {
+                    self.$rstr.modify(|r| r | (1 << $bit));
+                    self.$rstr.modify(|r| r & !(1 << $bit));
+                }
+            }
+        )+
+    };
+}
+
 #[allow(dead_code)]
 pub struct Rcc 
 {
@@ -16,8 +36,106 @@ pub struct Rcc
 }
 
 
+impl<'a> super::Rcc<'a> {
+
+    /// Splits the RCC into its clock tree configuration and the handle that enables
+    /// and resets the peripherals.
+    ///
+    /// This consumes the RCC wrapper, so there is a single `Cfgr` and `Clocks` can only
+    /// be frozen once. Nothing is written to the RCC until `Cfgr::freeze()` is called.
+    ///
+    /// # Example
+    /// ```
+    /// let rcc = dp.rcc.constrain();
+    /// let gpioa = dp.gpioa.split(rcc.bus);
+    ///
+    /// // 72 MHz from an 8 MHz crystal
+    /// let clocks = rcc.cfgr
+    ///     .use_hse(8_000_000)
+    ///     .sysclk(72_000_000)
+    ///     .pclk1(36_000_000)
+    ///     .freeze(&dp.flash)?;
+    /// ```
+    pub fn constrain(self) -> Parts<'a> {
+        let rcc = unsafe { & *(0x4002_1000 as *const Rcc) };
+
+        Parts {
+            cfgr: Cfgr {
+                rcc,
+                config: ClockConfig::default(),
+            },
+            bus: rcc,
+        }
+    }
+}
+
+/// The RCC split by `constrain()`.
+pub struct Parts<'a>
+{
+    /// The clock tree configuration, consumed by `Cfgr::freeze()`.
+    pub cfgr: Cfgr<'a>,
+    /// Enables and resets the peripherals, e.g. in the GPIO `split()`.
+    pub bus:  &'a Rcc,
+}
+
 impl Rcc {
 
+    reset! {
+        apb2rstr: [
+            (reset_afio, 0, "AFIO"),
+            (reset_gpioa, 2, "GPIO port A"),
+            (reset_gpiob, 3, "GPIO port B"),
+            (reset_gpioc, 4, "GPIO port C"),
+            (reset_gpiod, 5, "GPIO port D"),
+            (reset_gpioe, 6, "GPIO port E"),
+            (reset_gpiof, 7, "GPIO port F"),
+            (reset_gpiog, 8, "GPIO port G"),
+            (reset_adc1, 9, "ADC1"),
+            (reset_adc2, 10, "ADC2"),
+            (reset_tim1, 11, "TIM1"),
+            (reset_spi1, 12, "SPI1"),
+            (reset_tim8, 13, "TIM8"),
+            (reset_usart1, 14, "USART1"),
+            (reset_adc3, 15, "ADC3"),
+            (reset_tim9, 19, "TIM9"),
+            (reset_tim10, 20, "TIM10"),
+            (reset_tim11, 21, "TIM11"),
+        ]
+    }
+
+    reset! {
+        apb1rstr: [
+            (reset_tim2, 0, "TIM2"),
+            (reset_tim3, 1, "TIM3"),
+            (reset_tim4, 2, "TIM4"),
+            (reset_tim5, 3, "TIM5"),
+            (reset_tim6, 4, "TIM6"),
+            (reset_tim7, 5, "TIM7"),
+            (reset_tim12, 6, "TIM12"),
+            (reset_tim13, 7, "TIM13"),
+            (reset_tim14, 8, "TIM14"),
+            (reset_wwdg, 11, "WWDG"),
+            (reset_spi2, 14, "SPI2"),
+            (reset_spi3, 15, "SPI3"),
+            (reset_usart2, 17, "USART2"),
+            (reset_usart3, 18, "USART3"),
+            (reset_uart4, 19, "UART4"),
+            (reset_uart5, 20, "UART5"),
+            (reset_i2c1, 21, "I2C1"),
+            (reset_i2c2, 22, "I2C2"),
+            (reset_usb, 23, "USB"),
+            (reset_can, 25, "CAN"),
+            (reset_bkp, 27, "BKP"),
+            (reset_pwr, 28, "PWR"),
+            (reset_dac, 29, "DAC"),
+        ]
+    }
+
+    #[inline(always)]
+    pub fn enable_afio(&self) {
+        unsafe { self.apb2enr.modify(|r| r | (1 << 0)) };
+    }
+
     #[inline(always)]
     pub fn enable_gpioa(&self) {
         unsafe { self.apb2enr.modify(|r| r | (1 << 2)) };
@@ -43,4 +161,490 @@ impl Rcc {
         unsafe { self.apb2enr.modify(|r| r | (1 << 6)) };
     }
 
+    #[inline(always)]
+    pub fn enable_gpiof(&self) {
+        unsafe { self.apb2enr.modify(|r| r | (1 << 7)) };
+    }
+
+    #[inline(always)]
+    pub fn enable_gpiog(&self) {
+        unsafe { self.apb2enr.modify(|r| r | (1 << 8)) };
+    }
+
+}
+
+/// Frequency of the internal RC oscillator, in Hz.
+pub const HSI: u32 = 8_000_000;
+
+const CR_HSION: u32 = 1 << 0;
+const CR_HSIRDY: u32 = 1 << 1;
+const CR_HSEON: u32 = 1 << 16;
+const CR_HSERDY: u32 = 1 << 17;
+const CR_HSEBYP: u32 = 1 << 18;
+const CR_PLLON: u32 = 1 << 24;
+const CR_PLLRDY: u32 = 1 << 25;
+
+const CFGR_SW_HSI: u32 = 0b00;
+const CFGR_SW_HSE: u32 = 0b01;
+const CFGR_SW_PLL: u32 = 0b10;
+const CFGR_HPRE_MASK: u32 = 0b1111 << 4;
+const CFGR_PLLSRC_HSE: u32 = 1 << 16;
+const CFGR_USBPRE_DIV1: u32 = 1 << 22;
+
+/// Bits of CFGR written by `Cfgr::freeze()`: SW, HPRE, PPRE1, PPRE2, ADCPRE, PLLSRC,
+/// PLLXTPRE, PLLMUL and USBPRE.
+const CFGR_MASK: u32 = 0x007F_FFF3;
+
+const SYSCLK_MAX: u32 = 72_000_000;
+const PCLK1_MAX: u32 = 36_000_000;
+const ADCCLK_MAX: u32 = 14_000_000;
+
+/// Error returned when a requested clock configuration cannot be reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClockError
+{
+    /// The HSE frequency is outside 4-16 MHz (crystal) or 1-25 MHz (bypass).
+    HseOutOfRange,
+    /// SYSCLK is above 72 MHz or cannot be produced from the PLL input (multiplier 2-16).
+    SysclkOutOfRange,
+}
+
+/// Clock tree configuration, taken from the `Parts` of `constrain()` and applied with
+/// `freeze()`.
+///
+/// All frequencies are in Hz. Bus frequencies that are not requested run as fast as
+/// their limit allows; requested ones are rounded down to the nearest reachable value.
+pub struct Cfgr<'a>
+{
+    rcc:    &'a Rcc,
+    config: ClockConfig,
+}
+
+/// Requested frequencies of a `Cfgr`.
+#[derive(Clone, Copy, Debug, Default)]
+struct ClockConfig
+{
+    hse:        Option<u32>,
+    hse_bypass: bool,
+    sysclk:     Option<u32>,
+    hclk:       Option<u32>,
+    pclk1:      Option<u32>,
+    pclk2:      Option<u32>,
+    adcclk:     Option<u32>,
+}
+
+/// Register settings computed from a `ClockConfig`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ClockPlan
+{
+    /// CFGR bits without SW: prescalers and PLL settings.
+    cfgr:   u32,
+    /// SW value selecting the SYSCLK source.
+    sw:     u32,
+    clocks: Clocks,
+}
+
+impl<'a> Cfgr<'a>
+{
+    /// Uses the external oscillator (HSE) of frequency `freq` as the clock source.
+    ///
+    /// When the PLL is needed, it is fed directly from the HSE instead of HSI/2.
+    pub fn use_hse(mut self, freq: u32) -> Self
+    {
+        self.config.hse = Some(freq);
+        self
+    }
+
+    /// Takes the HSE from an external clock on OSC_IN instead of a crystal.
+    pub fn bypass_hse_oscillator(mut self) -> Self
+    {
+        self.config.hse_bypass = true;
+        self
+    }
+
+    /// Sets the system clock frequency, at most 72 MHz.
+    ///
+    /// The PLL is used whenever `freq` differs from the oscillator frequency. It must
+    /// then be 2 to 16 times the PLL input (the HSE, or HSI/2 = 4 MHz); `freeze()`
+    /// returns `ClockError::SysclkOutOfRange` otherwise.
+    pub fn sysclk(mut self, freq: u32) -> Self
+    {
+        self.config.sysclk = Some(freq);
+        self
+    }
+
+    /// Sets the AHB clock frequency (HCLK).
+    pub fn hclk(mut self, freq: u32) -> Self
+    {
+        self.config.hclk = Some(freq);
+        self
+    }
+
+    /// Sets the APB1 clock frequency (PCLK1), at most 36 MHz.
+    pub fn pclk1(mut self, freq: u32) -> Self
+    {
+        self.config.pclk1 = Some(freq);
+        self
+    }
+
+    /// Sets the APB2 clock frequency (PCLK2).
+    pub fn pclk2(mut self, freq: u32) -> Self
+    {
+        self.config.pclk2 = Some(freq);
+        self
+    }
+
+    /// Sets the ADC clock frequency, at most 14 MHz.
+    pub fn adcclk(mut self, freq: u32) -> Self
+    {
+        self.config.adcclk = Some(freq);
+        self
+    }
+
+    /// Applies the configuration and returns the resulting frequencies.
+    ///
+    /// The HSE and the PLL are started and their ready flags awaited before SYSCLK is
+    /// switched over. If the PLL has to be reconfigured, SYSCLK is first moved back to
+    /// the HSI. The USB prescaler is chosen so that USBCLK is 48 MHz when the PLL runs
+    /// at 48 or 72 MHz.
+    ///
+    /// The flash wait states are set for the new SYSCLK: raised before the switch and
+    /// lowered after it, so the core never runs faster than the flash allows. Half-cycle
+    /// access is turned off when SYSCLK comes from the PLL or exceeds 8 MHz. The prefetch
+    /// buffer is turned back on if it was disabled, from the HSI, since an AHB prescaler
+    /// requires it.
+    ///
+    /// # Arguments
+    /// * `flash` - Reference to the `Flash` whose ACR holds the wait states.
+    ///
+    /// # Returns
+    /// The frozen `Clocks`, or a `ClockError` if the requested SYSCLK or HSE frequency
+    /// cannot be used. Nothing is written to the RCC on error.
+    pub fn freeze(self, flash: &Flash) -> Result<Clocks, ClockError>
+    {
+        let plan = self.config.plan()?;
+        let rcc = self.rcc;
+
+        if self.config.hse.is_some() {
+            unsafe {
+                rcc.cr.modify(|r| r & !CR_HSEON);
+                if self.config.hse_bypass {
+                    rcc.cr.modify(|r| r | CR_HSEBYP);
+                } else {
+                    rcc.cr.modify(|r| r & !CR_HSEBYP);
+                }
+                rcc.cr.modify(|r| r | CR_HSEON);
+            }
+            while rcc.cr.read() & CR_HSERDY == 0 {}
+        }
+
+        // the PLL can only be configured while it is off, and it can only be
+        // turned off while it is not the system clock
+        if rcc.cr.read() & CR_PLLON != 0 {
+            unsafe {
+                rcc.cr.modify(|r| r | CR_HSION);
+                while rcc.cr.read() & CR_HSIRDY == 0 {}
+                rcc.cfgr.modify(|r| (r & !0b11) | CFGR_SW_HSI);
+                while (rcc.cfgr.read() >> 2) & 0b11 != CFGR_SW_HSI {}
+                rcc.cr.modify(|r| r & !CR_PLLON);
+            }
+            while rcc.cr.read() & CR_PLLRDY != 0 {}
+        }
+
+        // the prefetch buffer is required with an AHB prescaler, and can only be
+        // switched below 24 MHz without one: turn it on from the undivided HSI
+        if !flash.is_prefetch_enabled() {
+            unsafe {
+                rcc.cr.modify(|r| r | CR_HSION);
+                while rcc.cr.read() & CR_HSIRDY == 0 {}
+                rcc.cfgr.modify(|r| (r & !(0b11 | CFGR_HPRE_MASK)) | CFGR_SW_HSI);
+                while (rcc.cfgr.read() >> 2) & 0b11 != CFGR_SW_HSI {}
+            }
+            flash.set_prefetch(true);
+            while !flash.is_prefetch_enabled() {}
+        }
+
+        // prescalers and PLL settings first, with SYSCLK still on its current source
+        unsafe { rcc.cfgr.modify(|r| (r & !CFGR_MASK) | (r & 0b11) | plan.cfgr) };
+
+        if plan.sw == CFGR_SW_PLL {
+            unsafe { rcc.cr.modify(|r| r | CR_PLLON) };
+            while rcc.cr.read() & CR_PLLRDY == 0 {}
+        }
+
+        let sysclk = plan.clocks.sysclk;
+        let latency = Latency::for_sysclk(sysclk);
+        if latency > flash.latency() {
+            flash.set_latency(latency);
+        }
+        if plan.sw == CFGR_SW_PLL || sysclk > HSI {
+            flash.set_half_cycle(false);
+        }
+
+        unsafe { rcc.cfgr.modify(|r| (r & !0b11) | plan.sw) };
+        while (rcc.cfgr.read() >> 2) & 0b11 != plan.sw {}
+
+        flash.set_latency(latency);
+
+        Ok(plan.clocks)
+    }
+}
+
+impl ClockConfig
+{
+    /// Computes the PLL, prescaler and SYSCLK source settings and the frequencies
+    /// they give.
+    fn plan(&self) -> Result<ClockPlan, ClockError>
+    {
+        if let Some(hse) = self.hse {
+            let (min, max) = if self.hse_bypass { (1_000_000, 25_000_000) } else { (4_000_000, 16_000_000) };
+            if hse < min || hse > max {
+                return Err(ClockError::HseOutOfRange);
+            }
+        }
+
+        let osc = self.hse.unwrap_or(HSI);
+        let sysclk = self.sysclk.unwrap_or(osc);
+        if sysclk > SYSCLK_MAX {
+            return Err(ClockError::SysclkOutOfRange);
+        }
+
+        // PLL input is HSE or HSI/2, PLLMUL goes from x2 (0b0000) to x16 (0b1110)
+        let pll_mul = if sysclk == osc {
+            None
+        } else {
+            let pll_in = self.hse.unwrap_or(HSI / 2);
+            let mul = sysclk / pll_in;
+            if !sysclk.is_multiple_of(pll_in) || !(2..=16).contains(&mul) {
+                return Err(ClockError::SysclkOutOfRange);
+            }
+            Some(mul)
+        };
+
+        let (hpre, hdiv) = prescaler(sysclk, self.hclk.unwrap_or(sysclk), &[
+            (0b0000, 1), (0b1000, 2), (0b1001, 4), (0b1010, 8), (0b1011, 16),
+            (0b1100, 64), (0b1101, 128), (0b1110, 256), (0b1111, 512),
+        ]);
+        let hclk = sysclk / hdiv;
+
+        let apb = [(0b000, 1), (0b100, 2), (0b101, 4), (0b110, 8), (0b111, 16)];
+        let (ppre1, pdiv1) = prescaler(hclk, self.pclk1.unwrap_or(hclk).min(PCLK1_MAX), &apb);
+        let (ppre2, pdiv2) = prescaler(hclk, self.pclk2.unwrap_or(hclk), &apb);
+        let pclk1 = hclk / pdiv1;
+        let pclk2 = hclk / pdiv2;
+
+        let (adcpre, adcdiv) = prescaler(pclk2, self.adcclk.unwrap_or(ADCCLK_MAX).min(ADCCLK_MAX), &[
+            (0b00, 2), (0b01, 4), (0b10, 6), (0b11, 8),
+        ]);
+        let adcclk = pclk2 / adcdiv;
+
+        let usbpre = match (pll_mul, sysclk) {
+            (Some(_), 48_000_000) => Some(CFGR_USBPRE_DIV1),
+            (Some(_), 72_000_000) => Some(0),
+            _ => None,
+        };
+
+        let mut cfgr = (hpre << 4) | (ppre1 << 8) | (ppre2 << 11) | (adcpre << 14) | usbpre.unwrap_or(0);
+        if let Some(mul) = pll_mul {
+            cfgr |= (mul - 2) << 18;
+            if self.hse.is_some() {
+                cfgr |= CFGR_PLLSRC_HSE;
+            }
+        }
+
+        let sw = match (pll_mul, self.hse) {
+            (Some(_), _) => CFGR_SW_PLL,
+            (None, Some(_)) => CFGR_SW_HSE,
+            (None, None) => CFGR_SW_HSI,
+        };
+
+        Ok(ClockPlan {
+            cfgr,
+            sw,
+            clocks: Clocks {
+                sysclk,
+                hclk,
+                pclk1,
+                pclk2,
+                ppre1: pdiv1 as u8,
+                ppre2: pdiv2 as u8,
+                adcclk,
+                usbclk_valid: usbpre.is_some(),
+            },
+        })
+    }
+}
+
+/// Picks the smallest divider of `table` bringing `input` down to at most `target`.
+///
+/// Returns the register bits and the divider; the largest divider is used if none fits.
+fn prescaler(input: u32, target: u32, table: &[(u32, u32)]) -> (u32, u32)
+{
+    table
+        .iter()
+        .copied()
+        .find(|&(_, div)| input / div <= target)
+        .unwrap_or(table[table.len() - 1])
+}
+
+/// Clock frequencies, in Hz, frozen by `Cfgr::freeze()`.
+///
+/// Drivers take a `Clocks` to compute baud rates and timer periods. It can only be
+/// obtained by applying a configuration, so it always matches the hardware.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clocks
+{
+    sysclk:       u32,
+    hclk:         u32,
+    pclk1:        u32,
+    pclk2:        u32,
+    ppre1:        u8,
+    ppre2:        u8,
+    adcclk:       u32,
+    usbclk_valid: bool,
+}
+
+impl Clocks
+{
+    /// System clock frequency.
+    pub fn sysclk(&self) -> u32
+    {
+        self.sysclk
+    }
+
+    /// AHB clock frequency.
+    pub fn hclk(&self) -> u32
+    {
+        self.hclk
+    }
+
+    /// APB1 clock frequency.
+    pub fn pclk1(&self) -> u32
+    {
+        self.pclk1
+    }
+
+    /// APB2 clock frequency.
+    pub fn pclk2(&self) -> u32
+    {
+        self.pclk2
+    }
+
+    /// Clock frequency of the APB1 timers (TIM2-7, TIM12-14).
+    ///
+    /// Timers run at twice PCLK1 when the APB1 prescaler is not 1.
+    pub fn pclk1_tim(&self) -> u32
+    {
+        if self.ppre1 == 1 { self.pclk1 } else { self.pclk1 * 2 }
+    }
+
+    /// Clock frequency of the APB2 timers (TIM1, TIM8-11).
+    ///
+    /// Timers run at twice PCLK2 when the APB2 prescaler is not 1.
+    pub fn pclk2_tim(&self) -> u32
+    {
+        if self.ppre2 == 1 { self.pclk2 } else { self.pclk2 * 2 }
+    }
+
+    /// ADC clock frequency.
+    pub fn adcclk(&self) -> u32
+    {
+        self.adcclk
+    }
+
+    /// Checks whether USBCLK runs at the 48 MHz the USB peripheral requires.
+    pub fn usbclk_valid(&self) -> bool
+    {
+        self.usbclk_valid
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn reset_configuration_runs_on_hsi()
+    {
+        let plan = ClockConfig::default().plan().unwrap();
+        assert_eq!(plan.sw, CFGR_SW_HSI);
+        assert_eq!(plan.cfgr, 0);
+        assert_eq!(plan.clocks.sysclk(), HSI);
+        assert_eq!(plan.clocks.pclk1(), HSI);
+        assert_eq!(plan.clocks.adcclk(), HSI / 2);
+        assert!(!plan.clocks.usbclk_valid());
+    }
+
+    #[test]
+    fn hse_8mhz_to_72mhz()
+    {
+        let plan = ClockConfig { hse: Some(8_000_000), sysclk: Some(72_000_000), ..Default::default() }.plan().unwrap();
+        assert_eq!(plan.sw, CFGR_SW_PLL);
+        // PLLMUL x9, PLLSRC HSE, USBPRE /1.5, ADCPRE /6, PPRE1 /2
+        assert_eq!(plan.cfgr, (0b0111 << 18) | CFGR_PLLSRC_HSE | (0b10 << 14) | (0b100 << 8));
+        assert_eq!(plan.clocks.hclk(), 72_000_000);
+        assert_eq!(plan.clocks.pclk1(), 36_000_000);
+        assert_eq!(plan.clocks.pclk1_tim(), 72_000_000);
+        assert_eq!(plan.clocks.pclk2(), 72_000_000);
+        assert_eq!(plan.clocks.pclk2_tim(), 72_000_000);
+        assert_eq!(plan.clocks.adcclk(), 12_000_000);
+        assert!(plan.clocks.usbclk_valid());
+    }
+
+    #[test]
+    fn hsi_pll_to_48mhz_allows_usb()
+    {
+        let plan = ClockConfig { sysclk: Some(48_000_000), ..Default::default() }.plan().unwrap();
+        assert_eq!(plan.cfgr & (0b1111 << 18), 0b1010 << 18);
+        assert_eq!(plan.cfgr & CFGR_PLLSRC_HSE, 0);
+        assert_eq!(plan.cfgr & CFGR_USBPRE_DIV1, CFGR_USBPRE_DIV1);
+        assert!(plan.clocks.usbclk_valid());
+    }
+
+    #[test]
+    fn sysclk_must_be_a_multiple_of_the_pll_input()
+    {
+        let config = ClockConfig { hse: Some(8_000_000), sysclk: Some(70_000_000), ..Default::default() };
+        assert_eq!(config.plan(), Err(ClockError::SysclkOutOfRange));
+        let config = ClockConfig { sysclk: Some(72_000_000), ..Default::default() };
+        assert_eq!(config.plan(), Err(ClockError::SysclkOutOfRange));
+        let config = ClockConfig { sysclk: Some(80_000_000), ..Default::default() };
+        assert_eq!(config.plan(), Err(ClockError::SysclkOutOfRange));
+    }
+
+    #[test]
+    fn hse_range_depends_on_bypass()
+    {
+        let config = ClockConfig { hse: Some(20_000_000), ..Default::default() };
+        assert_eq!(config.plan(), Err(ClockError::HseOutOfRange));
+        let config = ClockConfig { hse: Some(20_000_000), hse_bypass: true, ..Default::default() };
+        assert_eq!(config.plan().unwrap().sw, CFGR_SW_HSE);
+    }
+
+    #[test]
+    fn bus_frequencies_round_down()
+    {
+        let config = ClockConfig {
+            hse: Some(8_000_000),
+            sysclk: Some(72_000_000),
+            hclk: Some(30_000_000),
+            pclk2: Some(10_000_000),
+            ..Default::default()
+        };
+        let clocks = config.plan().unwrap().clocks;
+        assert_eq!(clocks.hclk(), 18_000_000);
+        assert_eq!(clocks.pclk1(), 18_000_000);
+        assert_eq!(clocks.pclk2(), 9_000_000);
+        assert_eq!(clocks.pclk2_tim(), 18_000_000);
+    }
+
+    #[test]
+    fn prescaler_picks_the_smallest_fitting_divider()
+    {
+        let table = [(0, 1), (1, 2), (2, 4)];
+        assert_eq!(prescaler(8, 8, &table), (0, 1));
+        assert_eq!(prescaler(8, 5, &table), (1, 2));
+        assert_eq!(prescaler(8, 1, &table), (2, 4));
+    }
 }
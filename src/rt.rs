@@ -19,6 +19,71 @@ extern "C"
     fn SysTick(); 
 }
 
+#[cfg(feature = "rt")]
+extern "C"
+{
+    fn WWDG();
+    fn PVD();
+    fn TAMPER();
+    fn RTC();
+    fn FLASH();
+    fn RCC();
+    fn EXTI0();
+    fn EXTI1();
+    fn EXTI2();
+    fn EXTI3();
+    fn EXTI4();
+    fn DMA1_CHANNEL1();
+    fn DMA1_CHANNEL2();
+    fn DMA1_CHANNEL3();
+    fn DMA1_CHANNEL4();
+    fn DMA1_CHANNEL5();
+    fn DMA1_CHANNEL6();
+    fn DMA1_CHANNEL7();
+    fn ADC1_2();
+    fn USB_HP_CAN_TX();
+    fn USB_LP_CAN_RX0();
+    fn CAN_RX1();
+    fn CAN_SCE();
+    fn EXTI9_5();
+    fn TIM1_BRK();
+    fn TIM1_UP();
+    fn TIM1_TRG_COM();
+    fn TIM1_CC();
+    fn TIM2();
+    fn TIM3();
+    fn TIM4();
+    fn I2C1_EV();
+    fn I2C1_ER();
+    fn I2C2_EV();
+    fn I2C2_ER();
+    fn SPI1();
+    fn SPI2();
+    fn USART1();
+    fn USART2();
+    fn USART3();
+    fn EXTI15_10();
+    fn RTCALARM();
+    fn USBWAKEUP();
+    fn TIM8_BRK();
+    fn TIM8_UP();
+    fn TIM8_TRG_COM();
+    fn TIM8_CC();
+    fn ADC3();
+    fn FSMC();
+    fn SDIO();
+    fn TIM5();
+    fn SPI3();
+    fn UART4();
+    fn UART5();
+    fn TIM6();
+    fn TIM7();
+    fn DMA2_CHANNEL1();
+    fn DMA2_CHANNEL2();
+    fn DMA2_CHANNEL3();
+    fn DMA2_CHANNEL4_5();
+}
+
 #[cfg(feature = "rt")]
 #[allow(private_interfaces)]
 #[link_section = ".vector_table.exceptions"]
@@ -40,6 +105,73 @@ pub static EXCEPTIONS: [Vector; 14] = [
     Vector { handler: SysTick },
 ];
 
+#[cfg(feature = "rt")]
+#[allow(private_interfaces)]
+#[link_section = ".vector_table.interrupts"]
+#[no_mangle]
+pub static INTERRUPTS: [Vector; 60] = [
+    Vector { handler: WWDG },
+    Vector { handler: PVD },
+    Vector { handler: TAMPER },
+    Vector { handler: RTC },
+    Vector { handler: FLASH },
+    Vector { handler: RCC },
+    Vector { handler: EXTI0 },
+    Vector { handler: EXTI1 },
+    Vector { handler: EXTI2 },
+    Vector { handler: EXTI3 },
+    Vector { handler: EXTI4 },
+    Vector { handler: DMA1_CHANNEL1 },
+    Vector { handler: DMA1_CHANNEL2 },
+    Vector { handler: DMA1_CHANNEL3 },
+    Vector { handler: DMA1_CHANNEL4 },
+    Vector { handler: DMA1_CHANNEL5 },
+    Vector { handler: DMA1_CHANNEL6 },
+    Vector { handler: DMA1_CHANNEL7 },
+    Vector { handler: ADC1_2 },
+    Vector { handler: USB_HP_CAN_TX },
+    Vector { handler: USB_LP_CAN_RX0 },
+    Vector { handler: CAN_RX1 },
+    Vector { handler: CAN_SCE },
+    Vector { handler: EXTI9_5 },
+    Vector { handler: TIM1_BRK },
+    Vector { handler: TIM1_UP },
+    Vector { handler: TIM1_TRG_COM },
+    Vector { handler: TIM1_CC },
+    Vector { handler: TIM2 },
+    Vector { handler: TIM3 },
+    Vector { handler: TIM4 },
+    Vector { handler: I2C1_EV },
+    Vector { handler: I2C1_ER },
+    Vector { handler: I2C2_EV },
+    Vector { handler: I2C2_ER },
+    Vector { handler: SPI1 },
+    Vector { handler: SPI2 },
+    Vector { handler: USART1 },
+    Vector { handler: USART2 },
+    Vector { handler: USART3 },
+    Vector { handler: EXTI15_10 },
+    Vector { handler: RTCALARM },
+    Vector { handler: USBWAKEUP },
+    Vector { handler: TIM8_BRK },
+    Vector { handler: TIM8_UP },
+    Vector { handler: TIM8_TRG_COM },
+    Vector { handler: TIM8_CC },
+    Vector { handler: ADC3 },
+    Vector { handler: FSMC },
+    Vector { handler: SDIO },
+    Vector { handler: TIM5 },
+    Vector { handler: SPI3 },
+    Vector { handler: UART4 },
+    Vector { handler: UART5 },
+    Vector { handler: TIM6 },
+    Vector { handler: TIM7 },
+    Vector { handler: DMA2_CHANNEL1 },
+    Vector { handler: DMA2_CHANNEL2 },
+    Vector { handler: DMA2_CHANNEL3 },
+    Vector { handler: DMA2_CHANNEL4_5 },
+];
+
 /// A macro to define the main entry point of the application.
 /// 
 /// # Example